@@ -1402,6 +1402,11 @@ mod tests {
                 multi_tenant: false,
                 max_llm_concurrent_per_user: None,
                 max_jobs_concurrent_per_user: None,
+                offline: false,
+                tool_cache_max_entries: 0,
+                tool_cache_ttl: Duration::from_secs(300),
+                max_tools_in_flight: None,
+                max_network_tools_in_flight: None,
             },
             deps,
             Arc::new(ChannelManager::new()),
@@ -2284,6 +2289,11 @@ mod tests {
                 multi_tenant: false,
                 max_llm_concurrent_per_user: None,
                 max_jobs_concurrent_per_user: None,
+                offline: false,
+                tool_cache_max_entries: 0,
+                tool_cache_ttl: Duration::from_secs(300),
+                max_tools_in_flight: None,
+                max_network_tools_in_flight: None,
             },
             deps,
             Arc::new(ChannelManager::new()),
@@ -2412,6 +2422,11 @@ mod tests {
                     multi_tenant: false,
                     max_llm_concurrent_per_user: None,
                     max_jobs_concurrent_per_user: None,
+                    offline: false,
+                    tool_cache_max_entries: 0,
+                    tool_cache_ttl: Duration::from_secs(300),
+                    max_tools_in_flight: None,
+                    max_network_tools_in_flight: None,
                 },
                 deps,
                 Arc::new(ChannelManager::new()),
@@ -1780,6 +1780,10 @@ async fn execute_routine_tool(
         .get(&tc.name)
         .await
         .ok_or_else(|| format!("Tool '{}' not found", tc.name))?;
+    if let Some(err) = crate::tools::execute::offline_violation(&ctx.tools, tool.as_ref(), job_ctx)
+    {
+        return Err(err.into());
+    }
     let normalized_params = prepare_tool_params(tool.as_ref(), &tc.arguments);
 
     // Validate tool parameters
@@ -1798,6 +1802,7 @@ async fn execute_routine_tool(
     }
 
     // Execute with per-tool timeout
+    let permit = ctx.tools.acquire_execution_permit(tool.as_ref()).await;
     let timeout = tool.execution_timeout();
     let start = std::time::Instant::now();
     let result = tokio::time::timeout(timeout, async {
@@ -1805,6 +1810,8 @@ async fn execute_routine_tool(
     })
     .await;
     let elapsed = start.elapsed();
+    drop(permit);
+    let result = result.map(|r| r.map(|output| output.with_attribution(tool.name(), elapsed)));
 
     // Log tool execution result (single consolidated log)
     match &result {
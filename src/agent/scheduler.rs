@@ -803,6 +803,11 @@ mod tests {
             multi_tenant: false,
             max_llm_concurrent_per_user: None,
             max_jobs_concurrent_per_user: None,
+            offline: false,
+            tool_cache_max_entries: 0,
+            tool_cache_ttl: Duration::from_secs(300),
+            max_tools_in_flight: None,
+            max_network_tools_in_flight: None,
         };
         let cm = Arc::new(ContextManager::new(5));
         let llm: Arc<dyn LlmProvider> = Arc::new(StubLlm);
@@ -307,14 +307,26 @@ impl AppBuilder {
 
         // Initialize tool registry with credential injection support
         let credential_registry = Arc::new(SharedCredentialRegistry::new());
-        let tools = if let Some(ref ss) = self.secrets_store {
-            Arc::new(
-                ToolRegistry::new()
-                    .with_credentials(Arc::clone(&credential_registry), Arc::clone(ss)),
-            )
+        let mut registry = ToolRegistry::new()
+            .with_offline(self.config.agent.offline)
+            .with_result_cache(
+                self.config.agent.tool_cache_max_entries,
+                self.config.agent.tool_cache_ttl,
+            );
+        if let Some(limit) = self.config.agent.max_tools_in_flight {
+            registry = registry.with_concurrency_limit(limit);
+        }
+        if let Some(limit) = self.config.agent.max_network_tools_in_flight {
+            registry = registry.with_network_concurrency_limit(limit);
+        }
+        let tools = Arc::new(if let Some(ref ss) = self.secrets_store {
+            registry.with_credentials(Arc::clone(&credential_registry), Arc::clone(ss))
         } else {
-            Arc::new(ToolRegistry::new())
-        };
+            registry
+        });
+        if self.config.agent.offline {
+            tracing::info!("Offline mode: network-capable tools will be refused");
+        }
         tools.register_builtin_tools();
         tools.register_tool_info();
 
@@ -42,6 +42,21 @@ pub struct AgentConfig {
     pub max_llm_concurrent_per_user: Option<usize>,
     /// Maximum concurrent jobs per user. None = use default (3).
     pub max_jobs_concurrent_per_user: Option<usize>,
+    /// Air-gapped mode: refuse every tool that makes outbound network
+    /// requests. Set via AGENT_OFFLINE env var.
+    pub offline: bool,
+    /// Results kept per cacheable tool (0 disables caching). Set via
+    /// AGENT_TOOL_CACHE_MAX_ENTRIES env var.
+    pub tool_cache_max_entries: usize,
+    /// How long a cached tool result stays valid. Set via
+    /// AGENT_TOOL_CACHE_TTL_SECS env var.
+    pub tool_cache_ttl: Duration,
+    /// Maximum tools executing at once across all jobs. None = unlimited.
+    /// Set via AGENT_MAX_TOOLS_IN_FLIGHT env var.
+    pub max_tools_in_flight: Option<usize>,
+    /// Maximum network tools executing at once, on top of the overall
+    /// limit. None = unlimited. Set via AGENT_MAX_NETWORK_TOOLS_IN_FLIGHT.
+    pub max_network_tools_in_flight: Option<usize>,
 }
 
 impl AgentConfig {
@@ -69,6 +84,11 @@ impl AgentConfig {
             multi_tenant: false,
             max_llm_concurrent_per_user: None,
             max_jobs_concurrent_per_user: None,
+            offline: false,
+            tool_cache_max_entries: 0,
+            tool_cache_ttl: crate::tools::caching::DEFAULT_TTL,
+            max_tools_in_flight: None,
+            max_network_tools_in_flight: None,
         }
     }
 
@@ -133,6 +153,17 @@ impl AgentConfig {
             multi_tenant: parse_bool_env("AGENT_MULTI_TENANT", false)?,
             max_llm_concurrent_per_user: parse_option_env("TENANT_MAX_LLM_CONCURRENT")?,
             max_jobs_concurrent_per_user: parse_option_env("TENANT_MAX_JOBS_CONCURRENT")?,
+            offline: parse_bool_env("AGENT_OFFLINE", false)?,
+            tool_cache_max_entries: parse_optional_env(
+                "AGENT_TOOL_CACHE_MAX_ENTRIES",
+                crate::tools::caching::DEFAULT_MAX_ENTRIES,
+            )?,
+            tool_cache_ttl: Duration::from_secs(parse_optional_env(
+                "AGENT_TOOL_CACHE_TTL_SECS",
+                crate::tools::caching::DEFAULT_TTL.as_secs(),
+            )?),
+            max_tools_in_flight: parse_option_env("AGENT_MAX_TOOLS_IN_FLIGHT")?,
+            max_network_tools_in_flight: parse_option_env("AGENT_MAX_NETWORK_TOOLS_IN_FLIGHT")?,
         })
     }
 }
@@ -150,6 +181,30 @@ mod tests {
         assert!(result.is_err(), "invalid IANA timezone should be rejected");
     }
 
+    #[test]
+    fn test_tool_concurrency_limits_from_env() {
+        let _guard = crate::config::helpers::lock_env();
+        let settings = Settings::default();
+
+        // SAFETY: Under ENV_MUTEX, no concurrent env access.
+        unsafe {
+            std::env::set_var("AGENT_MAX_TOOLS_IN_FLIGHT", "8");
+            std::env::set_var("AGENT_MAX_NETWORK_TOOLS_IN_FLIGHT", "2");
+        }
+        let cfg = AgentConfig::resolve(&settings);
+        unsafe {
+            std::env::remove_var("AGENT_MAX_TOOLS_IN_FLIGHT");
+            std::env::remove_var("AGENT_MAX_NETWORK_TOOLS_IN_FLIGHT");
+        }
+        let cfg = cfg.expect("resolve");
+        assert_eq!(cfg.max_tools_in_flight, Some(8));
+        assert_eq!(cfg.max_network_tools_in_flight, Some(2));
+
+        let cfg = AgentConfig::resolve(&settings).expect("resolve");
+        assert_eq!(cfg.max_tools_in_flight, None);
+        assert_eq!(cfg.max_network_tools_in_flight, None);
+    }
+
     #[test]
     fn test_default_timezone_accepts_valid() {
         let settings = Settings::default(); // default is "UTC"
@@ -305,6 +305,23 @@ mod tests {
         assert_eq!(truncate_str(s, 4), "caf"); // safety: test
     }
 
+    #[test]
+    fn test_truncate_str_four_byte_emoji() {
+        // "🔬" is 4 bytes: every cut inside it must back up to the "ab" prefix.
+        let s = "ab🔬cd";
+        for max_len in 3..=5 {
+            assert_eq!(truncate_str(s, max_len), "ab"); // safety: test
+        }
+        assert_eq!(truncate_str(s, 6), "ab🔬"); // safety: test
+        // Mixed accents and emoji right at the limit never split a char.
+        let mixed = "é🔬é";
+        for max_len in 0..=mixed.len() {
+            let out = truncate_str(mixed, max_len);
+            assert!(out.len() <= max_len); // safety: test
+            assert!(mixed.starts_with(out)); // safety: test
+        }
+    }
+
     #[test]
     fn test_fallback_serialization() {
         let ctx = JobContext::new("Test", "Serialize");
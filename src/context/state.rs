@@ -199,6 +199,9 @@ pub struct JobContext {
     pub tool_output_stash: Arc<tokio::sync::RwLock<HashMap<String, String>>>,
     /// User's preferred timezone (IANA name, e.g. "America/New_York"). Defaults to "UTC".
     pub user_timezone: String,
+    /// When true, tools that declare `requires_network()` are refused before
+    /// they run. Used for air-gapped deployments.
+    pub offline: bool,
 }
 
 impl JobContext {
@@ -240,6 +243,7 @@ impl JobContext {
             metadata: serde_json::Value::Null,
             tool_output_stash: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             user_timezone: "UTC".to_string(),
+            offline: false,
         }
     }
 
@@ -249,6 +253,12 @@ impl JobContext {
         self
     }
 
+    /// Refuse network-capable tools for this job.
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
     /// Set the channel-specific requester/actor ID.
     pub fn with_requester_id(mut self, requester_id: impl Into<String>) -> Self {
         self.requester_id = Some(requester_id.into());
@@ -543,6 +543,41 @@ impl WorkspaceStore for LibSqlBackend {
         Ok(paths)
     }
 
+    async fn list_document_sizes(
+        &self,
+        user_id: &str,
+        agent_id: Option<Uuid>,
+    ) -> Result<Vec<(String, u64)>, WorkspaceError> {
+        let conn = self
+            .connect()
+            .await
+            .map_err(|e| WorkspaceError::SearchFailed {
+                reason: e.to_string(),
+            })?;
+        let agent_id_str = agent_id.map(|id| id.to_string());
+        let mut rows = conn
+            .query(
+                "SELECT path, length(CAST(content AS BLOB)) FROM memory_documents WHERE user_id = ?1 AND agent_id IS ?2 ORDER BY path",
+                params![user_id, agent_id_str.as_deref()],
+            )
+            .await
+            .map_err(|e| WorkspaceError::SearchFailed {
+                reason: format!("List document sizes failed: {}", e),
+            })?;
+
+        let mut sizes = Vec::new();
+        while let Some(row) = rows
+            .next()
+            .await
+            .map_err(|e| WorkspaceError::SearchFailed {
+                reason: format!("Query failed: {}", e),
+            })?
+        {
+            sizes.push((get_text(&row, 0), get_i64(&row, 1).max(0) as u64));
+        }
+        Ok(sizes)
+    }
+
     async fn list_documents(
         &self,
         user_id: &str,
@@ -679,6 +679,26 @@ pub trait WorkspaceStore: Send + Sync {
         user_id: &str,
         agent_id: Option<Uuid>,
     ) -> Result<Vec<MemoryDocument>, WorkspaceError>;
+
+    /// List every document path with its content size in bytes.
+    ///
+    /// The default implementation loads full documents. Backends override it
+    /// to compute sizes in SQL so contents are never transferred.
+    async fn list_document_sizes(
+        &self,
+        user_id: &str,
+        agent_id: Option<Uuid>,
+    ) -> Result<Vec<(String, u64)>, WorkspaceError> {
+        let docs = self.list_documents(user_id, agent_id).await?;
+        Ok(docs
+            .into_iter()
+            .map(|doc| {
+                let size = doc.content.len() as u64;
+                (doc.path, size)
+            })
+            .collect())
+    }
+
     async fn delete_chunks(&self, document_id: Uuid) -> Result<(), WorkspaceError>;
     async fn insert_chunk(
         &self,
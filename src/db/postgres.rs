@@ -703,6 +703,14 @@ impl WorkspaceStore for PgBackend {
         self.repo.list_documents(user_id, agent_id).await
     }
 
+    async fn list_document_sizes(
+        &self,
+        user_id: &str,
+        agent_id: Option<Uuid>,
+    ) -> Result<Vec<(String, u64)>, WorkspaceError> {
+        self.repo.list_document_sizes(user_id, agent_id).await
+    }
+
     async fn delete_chunks(&self, document_id: Uuid) -> Result<(), WorkspaceError> {
         self.repo.delete_chunks(document_id).await
     }
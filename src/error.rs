@@ -171,16 +171,24 @@ pub enum ToolError {
     #[error("Tool {name} is not available for autonomous execution: {reason}")]
     AutonomousUnavailable { name: String, reason: String },
 
-    #[error("Tool {name} is rate limited, retry after {retry_after:?}")]
+    #[error("Tool {name} is rate limited, retry after {retry_after:?}{}", message_suffix(.message))]
     RateLimited {
         name: String,
         retry_after: Option<Duration>,
+        message: Option<String>,
     },
 
     #[error("Tool builder failed: {0}")]
     BuilderFailed(String),
 }
 
+fn message_suffix(message: &Option<String>) -> String {
+    message
+        .as_deref()
+        .map(|m| format!(": {}", m))
+        .unwrap_or_default()
+}
+
 /// Safety/sanitization errors.
 #[derive(Debug, thiserror::Error)]
 pub enum SafetyError {
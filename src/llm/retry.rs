@@ -88,22 +88,28 @@ pub(crate) fn cap_retry_after(duration: Duration) -> Duration {
 pub(crate) fn parse_retry_after(header: Option<&reqwest::header::HeaderValue>) -> Duration {
     header
         .and_then(|v| v.to_str().ok())
-        .and_then(|v| {
-            if let Ok(secs) = v.trim().parse::<u64>() {
-                return Some(cap_retry_after(Duration::from_secs(secs)));
-            }
-            if let Ok(dt) = chrono::DateTime::parse_from_rfc2822(v.trim()) {
-                let now = chrono::Utc::now();
-                let delta = dt.signed_duration_since(now);
-                return Some(cap_retry_after(Duration::from_secs(
-                    delta.num_seconds().max(0) as u64,
-                )));
-            }
-            None
-        })
+        .and_then(parse_retry_after_value)
         .unwrap_or(Duration::from_secs(DEFAULT_RETRY_AFTER_SECS))
 }
 
+/// Parse a raw `Retry-After` value, returning `None` if it is unparseable.
+///
+/// Same formats and cap as [`parse_retry_after`], without the default.
+pub(crate) fn parse_retry_after_value(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(cap_retry_after(Duration::from_secs(secs)));
+    }
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc2822(value) {
+        let now = chrono::Utc::now();
+        let delta = dt.signed_duration_since(now);
+        return Some(cap_retry_after(Duration::from_secs(
+            delta.num_seconds().max(0) as u64,
+        )));
+    }
+    None
+}
+
 const DEFAULT_RETRY_AFTER_SECS: u64 = 60;
 
 /// Configuration for the retry decorator.
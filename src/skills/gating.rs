@@ -3,8 +3,91 @@
 //! Checks that a skill's declared requirements (binaries, environment variables,
 //! config files) are satisfied before the skill is loaded.
 
+use std::collections::{HashMap, HashSet};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use regex::Regex;
+
 use crate::skills::GatingRequirements;
 
+/// Compiled `env_matches` patterns, keyed by source. Skill manifests are
+/// re-checked on every reload, so each distinct pattern is compiled once.
+static ENV_PATTERNS: LazyLock<Mutex<HashMap<String, Result<Regex, String>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// A single unmet requirement, kept structured so callers can suggest fixes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GatingFailure {
+    /// A binary from `bins` is not on PATH.
+    MissingBinary(String),
+    /// No binary from an `any_of_bins` group is on PATH.
+    MissingAnyOf(Vec<String>),
+    /// An environment variable is not set.
+    MissingEnv(String),
+    /// An environment variable is set but its value does not match. The
+    /// value is deliberately not recorded: it may be a secret, and failures
+    /// end up in logs.
+    EnvMismatch { var: String, pattern: String },
+    /// An `env_matches` pattern failed to compile.
+    InvalidEnvPattern { var: String, error: String },
+    /// A config file path does not exist.
+    MissingConfig(String),
+    /// A config file exists but does not parse in its declared format.
+    InvalidConfig {
+        path: String,
+        format: String,
+        error: String,
+    },
+    /// A module from `python_packages` cannot be imported.
+    MissingPythonPackage(String),
+    /// A `config_valid` entry names a format the checker doesn't know.
+    UnsupportedConfigFormat { path: String, format: String },
+    /// The check itself could not run.
+    CheckFailed(String),
+}
+
+impl std::fmt::Display for GatingFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingBinary(bin) => write!(f, "required binary not found: {}", bin),
+            Self::MissingAnyOf(group) => write!(
+                f,
+                "none of the alternative binaries found: {}",
+                group.join(", ")
+            ),
+            Self::MissingEnv(var) => write!(f, "required env var not set: {}", var),
+            Self::EnvMismatch { var, pattern } => {
+                write!(f, "env {} does not match {}", var, pattern)
+            }
+            Self::InvalidEnvPattern { var, error } => {
+                write!(f, "invalid env pattern for {}: {}", var, error)
+            }
+            Self::MissingConfig(path) => write!(f, "required config not found: {}", path),
+            Self::InvalidConfig {
+                path,
+                format,
+                error,
+            } => write!(
+                f,
+                "config {} is not valid {}: {}",
+                path,
+                format.to_uppercase(),
+                error
+            ),
+            Self::MissingPythonPackage(module) => {
+                write!(f, "required python package not importable: {}", module)
+            }
+            Self::UnsupportedConfigFormat { path, format } => write!(
+                f,
+                "config {} has unsupported format '{}' (expected json, yaml, or toml)",
+                path, format
+            ),
+            Self::CheckFailed(message) => f.write_str(message),
+        }
+    }
+}
+
 /// Result of a gating check.
 #[derive(Debug)]
 pub struct GatingResult {
@@ -12,6 +95,18 @@ pub struct GatingResult {
     pub passed: bool,
     /// Descriptions of failed requirements.
     pub failures: Vec<String>,
+    /// Structured form of `failures`, in the same order.
+    pub details: Vec<GatingFailure>,
+}
+
+impl GatingResult {
+    fn from_failures(details: Vec<GatingFailure>) -> Self {
+        Self {
+            passed: details.is_empty(),
+            failures: details.iter().map(ToString::to_string).collect(),
+            details,
+        }
+    }
 }
 
 /// Async wrapper around [`check_requirements_sync`] that offloads blocking
@@ -30,47 +125,419 @@ pub async fn check_requirements(requirements: &GatingRequirements) -> GatingResu
                 format!("gating check failed to join: {}", e)
             };
             tracing::error!("{}", message);
-            GatingResult {
-                passed: false,
-                failures: vec![message],
-            }
+            GatingResult::from_failures(vec![GatingFailure::CheckFailed(message)])
         })
 }
 
 /// Check whether gating requirements are satisfied (synchronous).
 ///
 /// - `bins`: checks that each binary is findable via `which` (PATH lookup).
+/// - `any_of_bins`: checks that each group has at least one binary on PATH.
 /// - `env`: checks that each environment variable is set.
+/// - `env_matches`: checks that each environment variable is set and its value
+///   matches the given regex. Invalid patterns are reported as failures.
 /// - `config`: checks that each config file path exists.
+/// - `config_valid`: checks that each config file exists and parses as its
+///   declared format (`json`, `yaml`, or `toml`).
+/// - `python_packages`: checks that each module imports under `python3`.
 ///
 /// Skills that fail gating should be logged and skipped, not loaded.
 ///
 /// This is the synchronous implementation; prefer the async [`check_requirements`]
 /// wrapper when calling from async contexts to avoid blocking the tokio runtime.
 pub fn check_requirements_sync(requirements: &GatingRequirements) -> GatingResult {
+    check_with_probe_cache(requirements, &mut HashMap::new())
+}
+
+/// Check requirements, looking up each binary at most once per `probes` cache.
+fn check_with_probe_cache(
+    requirements: &GatingRequirements,
+    probes: &mut HashMap<String, bool>,
+) -> GatingResult {
     let mut failures = Vec::new();
+    let mut has_binary = |bin: &str| -> bool {
+        if let Some(&found) = probes.get(bin) {
+            return found;
+        }
+        let found = binary_exists(bin);
+        probes.insert(bin.to_string(), found);
+        found
+    };
 
     for bin in &requirements.bins {
-        if !binary_exists(bin) {
-            failures.push(format!("required binary not found: {}", bin));
+        if !has_binary(bin) {
+            failures.push(GatingFailure::MissingBinary(bin.clone()));
+        }
+    }
+
+    for group in &requirements.any_of_bins {
+        if !group.is_empty() && !group.iter().any(|bin| has_binary(bin)) {
+            failures.push(GatingFailure::MissingAnyOf(group.clone()));
         }
     }
 
     for var in &requirements.env {
         if std::env::var(var).is_err() {
-            failures.push(format!("required env var not set: {}", var));
+            failures.push(GatingFailure::MissingEnv(var.clone()));
+        }
+    }
+
+    let mut env_matches: Vec<_> = requirements.env_matches.iter().collect();
+    env_matches.sort();
+    for (var, pattern) in env_matches {
+        let re = match compiled_env_pattern(pattern) {
+            Ok(re) => re,
+            Err(error) => {
+                failures.push(GatingFailure::InvalidEnvPattern {
+                    var: var.clone(),
+                    error,
+                });
+                continue;
+            }
+        };
+        match std::env::var(var) {
+            Ok(value) if re.is_match(&value) => {}
+            Ok(_) => failures.push(GatingFailure::EnvMismatch {
+                var: var.clone(),
+                pattern: pattern.clone(),
+            }),
+            Err(_) => failures.push(GatingFailure::MissingEnv(var.clone())),
         }
     }
 
     for path in &requirements.config {
         if !std::path::Path::new(path).exists() {
-            failures.push(format!("required config not found: {}", path));
+            failures.push(GatingFailure::MissingConfig(path.clone()));
         }
     }
 
-    GatingResult {
-        passed: failures.is_empty(),
-        failures,
+    let mut config_valid: Vec<_> = requirements.config_valid.iter().collect();
+    config_valid.sort();
+    for (path, format) in config_valid {
+        if let Some(failure) = check_config_format(path, format) {
+            failures.push(failure);
+        }
+    }
+
+    for module in &requirements.python_packages {
+        if !is_python_module_name(module) {
+            failures.push(GatingFailure::CheckFailed(format!(
+                "invalid python package name: {:?}",
+                module
+            )));
+            continue;
+        }
+        let key = format!("python:{}", module);
+        let found = match probes.get(&key) {
+            Some(&found) => found,
+            None => {
+                let found = python_module_importable(module);
+                probes.insert(key, found);
+                found
+            }
+        };
+        if !found {
+            failures.push(GatingFailure::MissingPythonPackage(module.clone()));
+        }
+    }
+
+    GatingResult::from_failures(failures)
+}
+
+/// Whether `name` is a dotted Python identifier.
+fn is_python_module_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.split('.').all(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+}
+
+/// How long a Python module probe may run before it is killed.
+const PYTHON_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Check whether the system Python can find `module`.
+///
+/// Uses `importlib.util.find_spec` so the module itself is never imported
+/// (skill manifests may be untrusted), runs Python in isolated mode (`-I`)
+/// so a file in the working directory can't shadow the module, passes the
+/// name as an argument rather than code, and kills the probe after
+/// [`PYTHON_PROBE_TIMEOUT`].
+fn python_module_importable(module: &str) -> bool {
+    let python = if cfg!(windows) { "python" } else { "python3" };
+    let child = std::process::Command::new(python)
+        .args([
+            "-I",
+            "-c",
+            "import importlib.util,sys; sys.exit(importlib.util.find_spec(sys.argv[1]) is None)",
+            module,
+        ])
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(_) => return false,
+    };
+
+    let deadline = std::time::Instant::now() + PYTHON_PROBE_TIMEOUT;
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return status.success(),
+            Ok(None) if std::time::Instant::now() < deadline => {
+                std::thread::sleep(Duration::from_millis(20));
+            }
+            Ok(None) => {
+                tracing::warn!(module, "Python module probe timed out, killing it");
+                let _ = child.kill();
+                let _ = child.wait();
+                return false;
+            }
+            Err(_) => {
+                let _ = child.kill();
+                return false;
+            }
+        }
+    }
+}
+
+/// Compile `pattern`, reusing an earlier compilation of the same source.
+fn compiled_env_pattern(pattern: &str) -> Result<Regex, String> {
+    let mut cache = ENV_PATTERNS.lock().unwrap_or_else(|e| e.into_inner());
+    cache
+        .entry(pattern.to_string())
+        .or_insert_with(|| Regex::new(pattern).map_err(|e| e.to_string()))
+        .clone()
+}
+
+/// Read `path` and parse it as `format`, returning the failure if any.
+fn check_config_format(path: &str, format: &str) -> Option<GatingFailure> {
+    let format = format.to_ascii_lowercase();
+    if !matches!(format.as_str(), "json" | "yaml" | "yml" | "toml") {
+        return Some(GatingFailure::UnsupportedConfigFormat {
+            path: path.to_string(),
+            format,
+        });
+    }
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(_) => return Some(GatingFailure::MissingConfig(path.to_string())),
+    };
+    let parsed = match format.as_str() {
+        "json" => serde_json::from_str::<serde_json::Value>(&contents).map_err(|e| e.to_string()),
+        "toml" => toml::from_str::<toml::Value>(&contents).map_err(|e| e.to_string()),
+        _ => serde_yml::from_str::<serde_yml::Value>(&contents).map_err(|e| e.to_string()),
+    };
+    parsed.err().map(|error| GatingFailure::InvalidConfig {
+        path: path.to_string(),
+        format,
+        error,
+    })
+}
+
+/// Consolidated gating outcome for a set of skills.
+#[derive(Debug, Default)]
+pub struct BatchGatingReport {
+    /// Names of skills whose requirements are all met.
+    pub passed: Vec<String>,
+    /// Skills that failed, with their individual results.
+    pub failed: Vec<(String, GatingResult)>,
+    /// Unique remediation commands across all failed skills, in first-seen order.
+    pub remediation: Vec<String>,
+}
+
+/// Async wrapper around [`check_all_sync`] that runs the probes on the
+/// blocking thread pool, like [`check_requirements`].
+///
+/// Used by skill discovery so that loading a skill set yields one report.
+pub async fn check_all(requirements: Vec<(String, GatingRequirements)>) -> BatchGatingReport {
+    let names: Vec<String> = requirements.iter().map(|(name, _)| name.clone()).collect();
+    tokio::task::spawn_blocking(move || {
+        let refs: Vec<(&str, &GatingRequirements)> = requirements
+            .iter()
+            .map(|(name, reqs)| (name.as_str(), reqs))
+            .collect();
+        check_all_sync(&refs)
+    })
+    .await
+    .unwrap_or_else(|e| {
+        let message = format!("batch gating check failed to join: {}", e);
+        tracing::error!("{}", message);
+        BatchGatingReport {
+            failed: names
+                .into_iter()
+                .map(|name| {
+                    let failure = GatingFailure::CheckFailed(message.clone());
+                    (name, GatingResult::from_failures(vec![failure]))
+                })
+                .collect(),
+            ..Default::default()
+        }
+    })
+}
+
+/// Check several skills' requirements at once (synchronous).
+///
+/// Each binary is probed at most once across the whole batch, and the
+/// remediation steps from [`dry_check`] are merged so a prerequisite shared
+/// by several skills is listed once.
+pub fn check_all_sync(requirements: &[(&str, &GatingRequirements)]) -> BatchGatingReport {
+    let mut probes = HashMap::new();
+    let mut report = BatchGatingReport::default();
+    let mut seen = HashSet::new();
+
+    for (name, reqs) in requirements {
+        let result = check_with_probe_cache(reqs, &mut probes);
+        if result.passed {
+            report.passed.push(name.to_string());
+            continue;
+        }
+        for command in dry_check(&result) {
+            if seen.insert(command.clone()) {
+                report.remediation.push(command);
+            }
+        }
+        report.failed.push((name.to_string(), result));
+    }
+
+    report
+}
+
+/// Suggest shell commands that would fix the failures in a gating result.
+///
+/// Binaries get an install command for the platform's package manager (using
+/// a small per-manager map from binary to package), Python modules get a
+/// `pip install`, env vars get an `export` line, and config files get a
+/// comment naming the path to create. Nothing is executed.
+pub fn dry_check(result: &GatingResult) -> Vec<String> {
+    let mut commands = Vec::new();
+    for failure in &result.details {
+        match failure {
+            GatingFailure::MissingBinary(bin) => {
+                commands.push(PackageManager::current().install_command(bin))
+            }
+            GatingFailure::MissingAnyOf(group) => {
+                if let Some(first) = group.first() {
+                    commands.push(format!(
+                        "{}  # or any of: {}",
+                        PackageManager::current().install_command(first),
+                        group.join(", ")
+                    ));
+                }
+            }
+            GatingFailure::MissingPythonPackage(module) => {
+                commands.push(format!("pip install {}", pip_package_for_module(module)))
+            }
+            GatingFailure::MissingEnv(var) => commands.push(format!("export {}=...", var)),
+            GatingFailure::EnvMismatch { var, pattern, .. } => {
+                commands.push(format!("export {}=...  # must match {}", var, pattern))
+            }
+            GatingFailure::MissingConfig(path) => {
+                commands.push(format!("# create config file: {}", path))
+            }
+            GatingFailure::InvalidConfig { path, format, .. } => commands.push(format!(
+                "# fix config file: {} (must be valid {})",
+                path,
+                format.to_uppercase()
+            )),
+            // Fixing these means editing the skill manifest or retrying.
+            GatingFailure::InvalidEnvPattern { .. }
+            | GatingFailure::UnsupportedConfigFormat { .. }
+            | GatingFailure::CheckFailed(_) => {}
+        }
+    }
+    commands
+}
+
+/// System package manager used for binary install hints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PackageManager {
+    Apt,
+    Brew,
+    Winget,
+}
+
+impl PackageManager {
+    /// The conventional manager for the platform this was built for.
+    fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::Brew
+        } else if cfg!(windows) {
+            Self::Winget
+        } else {
+            Self::Apt
+        }
+    }
+
+    /// Package for binaries whose package is named differently under this
+    /// manager. `None` means "try the binary name".
+    fn package_for_binary(self, bin: &str) -> Option<&'static str> {
+        match self {
+            Self::Apt => match bin {
+                "rg" => Some("ripgrep"),
+                "fd" => Some("fd-find"),
+                "node" => Some("nodejs"),
+                "pip" | "pip3" => Some("python3-pip"),
+                "python" => Some("python3"),
+                "convert" | "magick" => Some("imagemagick"),
+                "psql" => Some("postgresql-client"),
+                _ => None,
+            },
+            Self::Brew => match bin {
+                "rg" => Some("ripgrep"),
+                "npm" => Some("node"),
+                "pip" | "pip3" | "python3" => Some("python"),
+                "convert" | "magick" => Some("imagemagick"),
+                "psql" => Some("libpq"),
+                _ => None,
+            },
+            // winget wants package IDs.
+            Self::Winget => match bin {
+                "rg" => Some("BurntSushi.ripgrep.MSVC"),
+                "fd" => Some("sharkdp.fd"),
+                "node" | "npm" => Some("OpenJS.NodeJS"),
+                "python" | "python3" | "pip" | "pip3" => Some("Python.Python.3.12"),
+                "convert" | "magick" => Some("ImageMagick.ImageMagick"),
+                "git" => Some("Git.Git"),
+                "jq" => Some("jqlang.jq"),
+                "gh" => Some("GitHub.cli"),
+                _ => None,
+            },
+        }
+    }
+
+    /// Install command for `bin`, falling back to the binary name.
+    fn install_command(self, bin: &str) -> String {
+        let mapped = self.package_for_binary(bin);
+        match self {
+            Self::Apt => format!("sudo apt-get install {}", mapped.unwrap_or(bin)),
+            Self::Brew => format!("brew install {}", mapped.unwrap_or(bin)),
+            Self::Winget => match mapped {
+                Some(id) => format!("winget install --id {} -e", id),
+                None => format!("winget install {}", bin),
+            },
+        }
+    }
+}
+
+/// PyPI distribution for an import name, for the common cases where they
+/// differ. Submodules install with their top-level package.
+fn pip_package_for_module(module: &str) -> &str {
+    let top = module.split('.').next().unwrap_or(module);
+    match top {
+        "sklearn" => "scikit-learn",
+        "cv2" => "opencv-python",
+        "yaml" => "pyyaml",
+        "PIL" => "pillow",
+        "bs4" => "beautifulsoup4",
+        "skimage" => "scikit-image",
+        "Bio" => "biopython",
+        "dateutil" => "python-dateutil",
+        _ => top,
     }
 }
 
@@ -120,6 +587,33 @@ mod tests {
         assert!(result.failures[0].contains("binary not found"));
     }
 
+    #[test]
+    fn test_any_of_bins_group_all_missing_fails() {
+        let req = GatingRequirements {
+            any_of_bins: vec![vec![
+                "__ironclaw_missing_a__".to_string(),
+                "__ironclaw_missing_b__".to_string(),
+            ]],
+            ..Default::default()
+        };
+        let result = check_requirements_sync(&req);
+        assert!(!result.passed);
+        assert_eq!(result.failures.len(), 1);
+        assert!(result.failures[0].contains("__ironclaw_missing_a__"));
+        assert!(result.failures[0].contains("__ironclaw_missing_b__"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_any_of_bins_group_with_one_present_passes() {
+        let req = GatingRequirements {
+            any_of_bins: vec![vec!["__ironclaw_missing_a__".to_string(), "sh".to_string()]],
+            ..Default::default()
+        };
+        let result = check_requirements_sync(&req);
+        assert!(result.passed, "failures: {:?}", result.failures);
+    }
+
     #[test]
     fn test_missing_env_var_fails() {
         let req = GatingRequirements {
@@ -142,6 +636,57 @@ mod tests {
         assert!(result.passed);
     }
 
+    #[test]
+    fn test_env_matches_matching_value_passes() {
+        let req = GatingRequirements {
+            env_matches: [("PATH".to_string(), ".+".to_string())].into(),
+            ..Default::default()
+        };
+        let result = check_requirements_sync(&req);
+        assert!(result.passed, "failures: {:?}", result.failures);
+    }
+
+    #[test]
+    fn test_env_matches_non_matching_value_fails() {
+        let req = GatingRequirements {
+            env_matches: [("PATH".to_string(), "^__never__$".to_string())].into(),
+            ..Default::default()
+        };
+        let result = check_requirements_sync(&req);
+        assert!(!result.passed);
+        assert_eq!(result.failures[0], "env PATH does not match ^__never__$");
+    }
+
+    #[test]
+    fn test_env_mismatch_does_not_leak_value() {
+        let _guard = crate::config::helpers::lock_env();
+        let var = "__IRONCLAW_TEST_GATING_SECRET__";
+        // SAFETY: serialized by ENV_MUTEX; the name is unique to this test.
+        unsafe { std::env::set_var(var, "sk-very-secret") };
+        let req = GatingRequirements {
+            env_matches: [(var.to_string(), "^pk-".to_string())].into(),
+            ..Default::default()
+        };
+        let result = check_requirements_sync(&req);
+        // SAFETY: serialized by ENV_MUTEX.
+        unsafe { std::env::remove_var(var) };
+
+        assert!(!result.passed);
+        assert!(!result.failures[0].contains("sk-very-secret"));
+        assert!(!format!("{:?}", result.details).contains("sk-very-secret"));
+    }
+
+    #[test]
+    fn test_env_matches_invalid_pattern_fails() {
+        let req = GatingRequirements {
+            env_matches: [("PATH".to_string(), "(unclosed".to_string())].into(),
+            ..Default::default()
+        };
+        let result = check_requirements_sync(&req);
+        assert!(!result.passed);
+        assert!(result.failures[0].contains("invalid env pattern for PATH"));
+    }
+
     #[test]
     fn test_missing_config_fails() {
         let req = GatingRequirements {
@@ -153,12 +698,180 @@ mod tests {
         assert!(result.failures[0].contains("config not found"));
     }
 
+    #[test]
+    fn test_dry_check_suggests_remediation() {
+        let result = GatingResult::from_failures(vec![
+            GatingFailure::MissingBinary("rg".to_string()),
+            GatingFailure::MissingEnv("API_KEY".to_string()),
+            GatingFailure::EnvMismatch {
+                var: "MODE".to_string(),
+                pattern: "^prod$".to_string(),
+            },
+        ]);
+
+        let commands = dry_check(&result);
+        assert_eq!(commands.len(), 3);
+        assert_eq!(commands[0], PackageManager::current().install_command("rg"));
+        assert_eq!(commands[1], "export API_KEY=...");
+        assert_eq!(commands[2], "export MODE=...  # must match ^prod$");
+    }
+
+    #[test]
+    fn test_dry_check_suggests_pip_for_missing_python_package() {
+        let result = GatingResult::from_failures(vec![
+            GatingFailure::MissingPythonPackage("numpy".to_string()),
+            GatingFailure::MissingPythonPackage("sklearn.linear_model".to_string()),
+        ]);
+        assert_eq!(
+            dry_check(&result),
+            vec!["pip install numpy", "pip install scikit-learn"]
+        );
+    }
+
+    #[test]
+    fn test_missing_python_package_fails() {
+        let req = GatingRequirements {
+            python_packages: vec!["__ironclaw_no_such_module__".to_string()],
+            ..Default::default()
+        };
+        let result = check_requirements_sync(&req);
+        assert!(!result.passed);
+        assert_eq!(
+            result.details,
+            vec![GatingFailure::MissingPythonPackage(
+                "__ironclaw_no_such_module__".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_python_probe_finds_stdlib_module() {
+        let python = if cfg!(windows) { "python" } else { "python3" };
+        if !binary_exists(python) {
+            return;
+        }
+        assert!(python_module_importable("json"));
+        assert!(python_module_importable("os.path"));
+        assert!(!python_module_importable("__ironclaw_no_such_module__"));
+    }
+
+    #[test]
+    fn test_python_package_name_is_validated() {
+        let req = GatingRequirements {
+            python_packages: vec!["os; import shutil".to_string()],
+            ..Default::default()
+        };
+        let result = check_requirements_sync(&req);
+        assert!(matches!(
+            result.details.as_slice(),
+            [GatingFailure::CheckFailed(_)]
+        ));
+    }
+
+    #[test]
+    fn test_install_commands_use_per_manager_names() {
+        assert_eq!(
+            PackageManager::Apt.install_command("fd"),
+            "sudo apt-get install fd-find"
+        );
+        assert_eq!(
+            PackageManager::Brew.install_command("fd"),
+            "brew install fd"
+        );
+        assert_eq!(
+            PackageManager::Brew.install_command("node"),
+            "brew install node"
+        );
+        assert_eq!(
+            PackageManager::Winget.install_command("rg"),
+            "winget install --id BurntSushi.ripgrep.MSVC -e"
+        );
+        // Unmapped binaries fall back to their own name.
+        assert_eq!(
+            PackageManager::Winget.install_command("ffmpeg"),
+            "winget install ffmpeg"
+        );
+        assert_eq!(
+            PackageManager::Apt.install_command("ffmpeg"),
+            "sudo apt-get install ffmpeg"
+        );
+    }
+
+    #[test]
+    fn test_dry_check_empty_for_passing_result() {
+        let result = check_requirements_sync(&GatingRequirements::default());
+        assert!(dry_check(&result).is_empty());
+    }
+
+    #[test]
+    fn test_config_valid_accepts_parseable_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("settings.json");
+        std::fs::write(&path, r#"{"model": "small", "threads": 4}"#).unwrap();
+        let req = GatingRequirements {
+            config_valid: [(path.display().to_string(), "json".to_string())].into(),
+            ..Default::default()
+        };
+        let result = check_requirements_sync(&req);
+        assert!(result.passed, "failures: {:?}", result.failures);
+    }
+
+    #[test]
+    fn test_config_valid_rejects_malformed_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("settings.json");
+        std::fs::write(&path, r#"{"model": "small",,}"#).unwrap();
+        let path = path.display().to_string();
+        let req = GatingRequirements {
+            config_valid: [(path.clone(), "json".to_string())].into(),
+            ..Default::default()
+        };
+        let result = check_requirements_sync(&req);
+        assert!(!result.passed);
+        assert!(
+            result.failures[0].starts_with(&format!("config {} is not valid JSON", path)),
+            "{}",
+            result.failures[0]
+        );
+    }
+
+    #[test]
+    fn test_check_all_merges_shared_remediation() {
+        let shared = "__ironclaw_missing_shared__".to_string();
+        let first = GatingRequirements {
+            bins: vec![shared.clone()],
+            ..Default::default()
+        };
+        let second = GatingRequirements {
+            bins: vec![shared.clone()],
+            env: vec!["__IRONCLAW_TEST_NONEXISTENT_VAR__".to_string()],
+            ..Default::default()
+        };
+        let empty = GatingRequirements::default();
+
+        let report = check_all_sync(&[("alpha", &first), ("beta", &second), ("gamma", &empty)]);
+
+        assert_eq!(report.passed, vec!["gamma".to_string()]);
+        let failed: Vec<&str> = report.failed.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(failed, vec!["alpha", "beta"]);
+        assert_eq!(report.remediation.len(), 2);
+        assert_eq!(
+            report
+                .remediation
+                .iter()
+                .filter(|c| c.contains(&shared))
+                .count(),
+            1
+        );
+    }
+
     #[test]
     fn test_multiple_mixed_requirements() {
         let req = GatingRequirements {
             bins: vec!["__no_such_bin__".to_string()],
             env: vec!["__NO_SUCH_VAR__".to_string()],
             config: vec!["/no/such/file".to_string()],
+            ..Default::default()
         };
         let result = check_requirements_sync(&req);
         assert!(!result.passed);
@@ -25,6 +25,7 @@ pub use attenuation::{AttenuationResult, attenuate_tools};
 pub use registry::SkillRegistry;
 pub use selector::prefilter_skills;
 
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use regex::{Regex, RegexBuilder};
@@ -180,12 +181,28 @@ pub struct GatingRequirements {
     /// Required binaries that must be on PATH.
     #[serde(default)]
     pub bins: Vec<String>,
+    /// Alternative binary groups: each group passes if at least one of its
+    /// binaries is on PATH (e.g. `[["docker", "podman"]]`).
+    #[serde(default)]
+    pub any_of_bins: Vec<Vec<String>>,
     /// Required environment variables that must be set.
     #[serde(default)]
     pub env: Vec<String>,
+    /// Environment variables whose value must match a regex
+    /// (e.g. `MODE: "^prod$"`). Unset variables fail.
+    #[serde(default)]
+    pub env_matches: HashMap<String, String>,
     /// Required config file paths that must exist.
     #[serde(default)]
     pub config: Vec<String>,
+    /// Config files that must exist and parse, mapped to their format
+    /// (`json`, `yaml`, or `toml`).
+    #[serde(default)]
+    pub config_valid: HashMap<String, String>,
+    /// Python modules that must be importable by the system interpreter,
+    /// by import name (e.g. `numpy`, `sklearn`).
+    #[serde(default)]
+    pub python_packages: Vec<String>,
 }
 
 /// A fully loaded skill ready for activation.
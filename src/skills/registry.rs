@@ -130,32 +130,45 @@ impl SkillRegistry {
     /// 1. Workspace skills directory (if set) -- Trusted
     /// 2. User skills directory -- Trusted
     /// 3. Installed skills directory (if set) -- Installed
+    ///
+    /// Each directory's skills are gated in one batch (see
+    /// [`gating::check_all`]) before the next directory is scanned, so
+    /// gated-out skills neither count toward the discovery cap nor shadow a
+    /// lower-priority skill of the same name. Failures from all directories
+    /// are reported together with suggested fixes.
     pub async fn discover_all(&mut self) -> Vec<String> {
         let mut loaded_names: Vec<String> = Vec::new();
         let mut seen: HashSet<String> = HashSet::new();
+        let mut report = gating::BatchGatingReport::default();
 
         // 1. Workspace skills (highest priority)
         if let Some(ws_dir) = self.workspace_dir.clone() {
-            let cap = MAX_DISCOVERED_SKILLS.saturating_sub(loaded_names.len());
             let skills = self
                 .discover_from_dir(
                     &ws_dir,
                     SkillTrust::Trusted,
                     &SkillSource::Workspace,
-                    cap,
+                    MAX_DISCOVERED_SKILLS,
                     0,
                 )
                 .await;
+            let skills = gate_discovered(skills, &mut report).await;
             self.absorb(skills, &mut seen, &mut loaded_names, "workspace");
         }
 
         // 2. User skills
         if loaded_names.len() < MAX_DISCOVERED_SKILLS {
-            let cap = MAX_DISCOVERED_SKILLS.saturating_sub(loaded_names.len());
             let user_dir = self.user_dir.clone();
             let skills = self
-                .discover_from_dir(&user_dir, SkillTrust::Trusted, &SkillSource::User, cap, 0)
+                .discover_from_dir(
+                    &user_dir,
+                    SkillTrust::Trusted,
+                    &SkillSource::User,
+                    MAX_DISCOVERED_SKILLS,
+                    0,
+                )
                 .await;
+            let skills = gate_discovered(skills, &mut report).await;
             self.absorb(skills, &mut seen, &mut loaded_names, "user/workspace");
         }
 
@@ -163,13 +176,21 @@ impl SkillRegistry {
         if loaded_names.len() < MAX_DISCOVERED_SKILLS
             && let Some(inst_dir) = self.installed_dir.clone()
         {
-            let cap = MAX_DISCOVERED_SKILLS.saturating_sub(loaded_names.len());
             let skills = self
-                .discover_from_dir(&inst_dir, SkillTrust::Installed, &SkillSource::User, cap, 0)
+                .discover_from_dir(
+                    &inst_dir,
+                    SkillTrust::Installed,
+                    &SkillSource::User,
+                    MAX_DISCOVERED_SKILLS,
+                    0,
+                )
                 .await;
+            let skills = gate_discovered(skills, &mut report).await;
             self.absorb(skills, &mut seen, &mut loaded_names, "user/workspace");
         }
 
+        log_gating_report(&report);
+
         if loaded_names.len() >= MAX_DISCOVERED_SKILLS {
             tracing::warn!(
                 "Global skill discovery cap reached ({} skills)",
@@ -189,6 +210,9 @@ impl SkillRegistry {
         override_source: &str,
     ) {
         for (name, skill) in skills {
+            if loaded_names.len() >= MAX_DISCOVERED_SKILLS {
+                break;
+            }
             if seen.contains(&name) {
                 tracing::debug!(
                     "Skipping skill '{}' (overridden by {})",
@@ -322,14 +346,14 @@ impl SkillRegistry {
         results
     }
 
-    /// Load a single SKILL.md file.
+    /// Load a single SKILL.md file without gating; discovery gates in bulk.
     async fn load_skill_md(
         &self,
         path: &Path,
         trust: SkillTrust,
         source: SkillSource,
     ) -> Result<(String, LoadedSkill), SkillRegistryError> {
-        load_and_validate_skill(path, trust, source).await
+        load_skill(path, trust, source).await
     }
 
     /// Get all loaded skills.
@@ -540,15 +564,118 @@ impl SkillRegistry {
     }
 }
 
-/// Load and validate a single SKILL.md file from disk.
+/// Load, validate and gate a single SKILL.md file from disk.
 ///
-/// Shared implementation used by both `SkillRegistry::load_skill_md` (discovery)
-/// and `SkillRegistry::prepare_install_to_disk` (installation). This avoids
-/// duplicating the read/parse/validate/hash pipeline.
+/// Used by `SkillRegistry::prepare_install_to_disk` (installation), where a
+/// skill is checked on its own. Discovery uses [`load_skill`] and gates the
+/// whole set in one batch instead.
 async fn load_and_validate_skill(
     path: &Path,
     trust: SkillTrust,
     source: SkillSource,
+) -> Result<(String, LoadedSkill), SkillRegistryError> {
+    let (name, skill) = load_skill(path, trust, source).await?;
+    if let Some(requires) = skill_requirements(&skill) {
+        let result = gating::check_requirements(requires).await;
+        if !result.passed {
+            return Err(SkillRegistryError::GatingFailed {
+                name,
+                reason: result.failures.join("; "),
+            });
+        }
+    }
+    Ok((name, skill))
+}
+
+/// The gating requirements declared in a skill's metadata, if any.
+fn skill_requirements(skill: &LoadedSkill) -> Option<&GatingRequirements> {
+    skill
+        .manifest
+        .metadata
+        .as_ref()
+        .and_then(|meta| meta.openclaw.as_ref())
+        .map(|openclaw| &openclaw.requires)
+}
+
+/// Unique key for a discovered skill in a batch gating report. Names can
+/// collide across directories, so the source path is included.
+fn gating_label(skill: &LoadedSkill) -> String {
+    let path = match &skill.source {
+        SkillSource::Workspace(p) | SkillSource::User(p) | SkillSource::Bundled(p) => p,
+    };
+    format!("{} ({})", skill.manifest.name, path.display())
+}
+
+/// Gate one directory's discovered skills in a single batch, dropping the
+/// ones that fail. Failures and remediation steps are merged into `report`
+/// so discovery can log them once at the end.
+async fn gate_discovered(
+    skills: Vec<(String, LoadedSkill)>,
+    report: &mut gating::BatchGatingReport,
+) -> Vec<(String, LoadedSkill)> {
+    let requirements: Vec<(String, GatingRequirements)> = skills
+        .iter()
+        .filter_map(|(_, skill)| {
+            skill_requirements(skill).map(|reqs| (gating_label(skill), reqs.clone()))
+        })
+        .collect();
+    if requirements.is_empty() {
+        return skills;
+    }
+
+    let batch = gating::check_all(requirements).await;
+    let failed: HashSet<&str> = batch
+        .failed
+        .iter()
+        .map(|(label, _)| label.as_str())
+        .collect();
+    let kept = skills
+        .into_iter()
+        .filter(|(_, skill)| !failed.contains(gating_label(skill).as_str()))
+        .collect();
+
+    report.passed.extend(batch.passed);
+    report.failed.extend(batch.failed);
+    for step in batch.remediation {
+        if !report.remediation.contains(&step) {
+            report.remediation.push(step);
+        }
+    }
+    kept
+}
+
+/// Log the skills skipped during discovery, with suggested fixes.
+fn log_gating_report(report: &gating::BatchGatingReport) {
+    if report.failed.is_empty() {
+        return;
+    }
+    let summary: Vec<String> = report
+        .failed
+        .iter()
+        .map(|(label, result)| format!("{}: {}", label, result.failures.join("; ")))
+        .collect();
+    tracing::warn!(
+        "{} skill(s) skipped, gating requirements not met:\n  {}",
+        report.failed.len(),
+        summary.join("\n  ")
+    );
+    if !report.remediation.is_empty() {
+        tracing::info!(
+            "To enable the skipped skills, try:\n  {}",
+            report.remediation.join("\n  ")
+        );
+    }
+}
+
+/// Load and validate a single SKILL.md file from disk, without gating.
+///
+/// Shared implementation used by both discovery and
+/// [`load_and_validate_skill`] (installation). This avoids duplicating the
+/// read/parse/validate/hash pipeline.
+async fn load_skill(
+    path: &Path,
+    trust: SkillTrust,
+    source: SkillSource,
 ) -> Result<(String, LoadedSkill), SkillRegistryError> {
     // Check for symlink at the file level
     let file_meta =
@@ -604,19 +731,6 @@ async fn load_and_validate_skill(
     let manifest = parsed.manifest;
     let prompt_content = parsed.prompt_content;
 
-    // Check gating requirements
-    if let Some(ref meta) = manifest.metadata
-        && let Some(ref openclaw) = meta.openclaw
-    {
-        let result = gating::check_requirements(&openclaw.requires).await;
-        if !result.passed {
-            return Err(SkillRegistryError::GatingFailed {
-                name: manifest.name.clone(),
-                reason: result.failures.join("; "),
-            });
-        }
-    }
-
     // Check token budget (reject if prompt is > 2x declared budget)
     // ~4 bytes per token for English prose = ~0.25 tokens per byte
     let approx_tokens = (prompt_content.len() as f64 * 0.25) as usize;
@@ -762,6 +876,42 @@ mod tests {
         assert!(loaded.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_batch_gating_skips_only_failing_skills() {
+        let ws = tempfile::tempdir().unwrap();
+        let user = tempfile::tempdir().unwrap();
+        let gated = "---\nname: shared-skill\nmetadata:\n  openclaw:\n    requires:\n      bins: [\"__nonexistent_bin__\"]\n---\n\nGated.\n";
+        let other = gated.replace("shared-skill", "other-gated");
+        for (root, name, content) in [
+            (ws.path(), "shared-skill", gated),
+            (ws.path(), "other-gated", other.as_str()),
+            (ws.path(), "plain", "---\nname: plain\n---\n\nPlain.\n"),
+            (
+                user.path(),
+                "shared-skill",
+                "---\nname: shared-skill\n---\n\nUser copy.\n",
+            ),
+        ] {
+            let skill_dir = root.join(name);
+            fs::create_dir(&skill_dir).unwrap();
+            fs::write(skill_dir.join("SKILL.md"), content).unwrap();
+        }
+
+        let mut registry = SkillRegistry::new(user.path().to_path_buf())
+            .with_workspace_dir(ws.path().to_path_buf());
+        let mut loaded = registry.discover_all().await;
+        loaded.sort();
+
+        // Both gated workspace skills are skipped, and the gated one no longer
+        // shadows the user skill of the same name.
+        assert_eq!(
+            loaded,
+            vec!["plain".to_string(), "shared-skill".to_string()]
+        );
+        let shared = registry.find_by_name("shared-skill").unwrap();
+        assert!(matches!(shared.source, SkillSource::User(_)));
+    }
+
     #[cfg(unix)]
     #[tokio::test]
     async fn test_symlink_rejected() {
@@ -1235,6 +1385,39 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_gated_out_skills_do_not_use_discovery_cap() {
+        let ws = tempfile::tempdir().unwrap();
+        let user = tempfile::tempdir().unwrap();
+
+        // A full cap's worth of workspace skills that all fail gating must not
+        // crowd out the user skills behind them.
+        for i in 0..MAX_DISCOVERED_SKILLS {
+            let skill_dir = ws.path().join(format!("gated-{:03}", i));
+            fs::create_dir(&skill_dir).unwrap();
+            fs::write(
+                skill_dir.join("SKILL.md"),
+                format!(
+                    "---\nname: gated-{:03}\nmetadata:\n  openclaw:\n    requires:\n      bins: [\"__nonexistent_bin__\"]\n---\n\nGated.\n",
+                    i
+                ),
+            )
+            .unwrap();
+        }
+        let skill_dir = user.path().join("user-skill");
+        fs::create_dir(&skill_dir).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: user-skill\n---\n\nUser.\n",
+        )
+        .unwrap();
+
+        let mut registry = SkillRegistry::new(user.path().to_path_buf())
+            .with_workspace_dir(ws.path().to_path_buf());
+        let loaded = registry.discover_all().await;
+        assert_eq!(loaded, vec!["user-skill".to_string()]);
+    }
+
     #[cfg(unix)]
     #[tokio::test]
     async fn test_symlink_rejected_in_nested_directory() {
@@ -0,0 +1,846 @@
+//! Shared reference library stored in the workspace.
+//!
+//! References live in a single JSON document (`bibliography/library.json`) so
+//! any document can cite a library key instead of inlining the full citation.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::context::JobContext;
+use crate::error::WorkspaceError;
+use crate::tools::builtin::memory::{FixedWorkspaceResolver, WorkspaceResolver};
+use crate::tools::tool::{Tool, ToolError, ToolOutput, require_str};
+use crate::workspace::{PathLock, Workspace};
+
+/// Workspace path of the library document.
+pub const LIBRARY_PATH: &str = "bibliography/library.json";
+
+/// Maximum authors listed in an APA reference before "et al.".
+const APA_MAX_AUTHORS: usize = 20;
+
+/// Maximum authors listed in a Vancouver reference before "et al.".
+const VANCOUVER_MAX_AUTHORS: usize = 6;
+
+/// Citation styles supported by [`Reference::format_citation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CitationStyle {
+    Apa,
+    Mla,
+    Vancouver,
+}
+
+impl CitationStyle {
+    /// Parse a style name (`apa`, `mla`, `vancouver`), case-insensitively.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "apa" => Some(Self::Apa),
+            "mla" => Some(Self::Mla),
+            "vancouver" => Some(Self::Vancouver),
+            _ => None,
+        }
+    }
+}
+
+/// A single bibliography entry.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Reference {
+    /// Citation key, unique within the library (e.g. `smith2024`).
+    pub key: String,
+    pub title: String,
+    #[serde(default)]
+    pub authors: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year: Option<i32>,
+    /// Bare, lowercased DOI (e.g. `10.1000/xyz123`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub venue: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl Reference {
+    /// Format as a plain-text citation: `Authors (Year). Title. Venue. DOI`.
+    pub fn citation(&self) -> String {
+        let authors = match self.authors.as_slice() {
+            [] => "Unknown author".to_string(),
+            [one] => one.clone(),
+            [first, second] => format!("{} & {}", first, second),
+            [first, second, third] => format!("{}, {} & {}", first, second, third),
+            [first, ..] => format!("{} et al.", first),
+        };
+        let year = self
+            .year
+            .map(|y| y.to_string())
+            .unwrap_or_else(|| "n.d.".to_string());
+        let mut citation = format!(
+            "{} ({}). {}.",
+            authors,
+            year,
+            self.title.trim_end_matches('.')
+        );
+        if let Some(venue) = &self.venue {
+            citation.push_str(&format!(" {}.", venue.trim_end_matches('.')));
+        }
+        if let Some(doi) = &self.doi {
+            citation.push_str(&format!(" https://doi.org/{}", doi));
+        } else if let Some(url) = &self.url {
+            citation.push_str(&format!(" {}", url));
+        }
+        citation
+    }
+
+    /// Format as a ready-to-paste reference in the given style.
+    ///
+    /// APA lists up to 20 authors and Vancouver up to 6 before "et al.";
+    /// MLA names the first author and uses "et al." from three authors on.
+    pub fn format_citation(&self, style: CitationStyle) -> String {
+        let title = self.title.trim().trim_end_matches('.');
+        let venue = self
+            .venue
+            .as_deref()
+            .map(|v| v.trim().trim_end_matches('.'));
+        let mut parts: Vec<String> = Vec::new();
+
+        match style {
+            CitationStyle::Apa => {
+                let names: Vec<String> = self
+                    .authors
+                    .iter()
+                    .map(|a| {
+                        let (surname, given) = name_parts(a);
+                        let initials = initials(&given, ". ");
+                        if initials.is_empty() {
+                            surname
+                        } else {
+                            format!("{}, {}.", surname, initials)
+                        }
+                    })
+                    .collect();
+                let year = self
+                    .year
+                    .map(|y| y.to_string())
+                    .unwrap_or_else(|| "n.d.".to_string());
+                let authors = match names.as_slice() {
+                    [] => None,
+                    [one] => Some(one.clone()),
+                    all if all.len() > APA_MAX_AUTHORS => {
+                        Some(format!("{}, et al.", all[..APA_MAX_AUTHORS].join(", ")))
+                    }
+                    [rest @ .., last] => Some(format!("{}, & {}", rest.join(", "), last)),
+                };
+                match authors {
+                    Some(authors) => {
+                        parts.push(format!("{} ({}).", authors, year));
+                        parts.push(format!("{}.", title));
+                    }
+                    None => parts.push(format!("{}. ({}).", title, year)),
+                }
+                if let Some(venue) = venue {
+                    parts.push(format!("{}.", venue));
+                }
+                if let Some(doi) = &self.doi {
+                    parts.push(format!("https://doi.org/{}", doi));
+                } else if let Some(url) = &self.url {
+                    parts.push(url.clone());
+                }
+            }
+            CitationStyle::Mla => {
+                let first = self.authors.first().map(|a| {
+                    let (surname, given) = name_parts(a);
+                    if given.is_empty() {
+                        surname
+                    } else {
+                        format!("{}, {}", surname, given.join(" "))
+                    }
+                });
+                let authors = match (first, self.authors.len()) {
+                    (None, _) => None,
+                    (Some(first), 1) => Some(first),
+                    (Some(first), 2) => {
+                        // Later authors are written in natural order.
+                        let (surname, given) = name_parts(&self.authors[1]);
+                        let second = given
+                            .into_iter()
+                            .chain(std::iter::once(surname))
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        Some(format!("{}, and {}", first, second))
+                    }
+                    (Some(first), _) => Some(format!("{}, et al", first)),
+                };
+                if let Some(authors) = authors {
+                    parts.push(format!("{}.", authors.trim_end_matches('.')));
+                }
+                parts.push(format!("\"{}.\"", title));
+                let mut container: Vec<String> = Vec::new();
+                container.extend(venue.map(str::to_string));
+                container.extend(self.year.map(|y| y.to_string()));
+                if let Some(doi) = &self.doi {
+                    container.push(format!("https://doi.org/{}", doi));
+                } else if let Some(url) = &self.url {
+                    container.push(url.clone());
+                }
+                if !container.is_empty() {
+                    parts.push(format!("{}.", container.join(", ")));
+                }
+            }
+            CitationStyle::Vancouver => {
+                let mut names: Vec<String> = self
+                    .authors
+                    .iter()
+                    .take(VANCOUVER_MAX_AUTHORS)
+                    .map(|a| {
+                        let (surname, given) = name_parts(a);
+                        let initials = initials(&given, "");
+                        if initials.is_empty() {
+                            surname
+                        } else {
+                            format!("{} {}", surname, initials)
+                        }
+                    })
+                    .collect();
+                if self.authors.len() > VANCOUVER_MAX_AUTHORS {
+                    names.push("et al".to_string());
+                }
+                if !names.is_empty() {
+                    parts.push(format!("{}.", names.join(", ")));
+                }
+                parts.push(format!("{}.", title));
+                if let Some(venue) = venue {
+                    parts.push(format!("{}.", venue));
+                }
+                if let Some(year) = self.year {
+                    parts.push(format!("{}.", year));
+                }
+                if let Some(doi) = &self.doi {
+                    parts.push(format!("doi:{}", doi));
+                } else if let Some(url) = &self.url {
+                    parts.push(format!("Available from: {}", url));
+                }
+            }
+        }
+
+        parts.join(" ")
+    }
+
+    fn matches(&self, needle: &str) -> bool {
+        let contains = |s: &str| s.to_lowercase().contains(needle);
+        contains(&self.key)
+            || contains(&self.title)
+            || self.authors.iter().any(|a| contains(a))
+            || self.venue.as_deref().is_some_and(contains)
+            || self.doi.as_deref().is_some_and(contains)
+    }
+}
+
+/// On-disk shape of the library document.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Library {
+    #[serde(default)]
+    references: Vec<Reference>,
+}
+
+/// Reduce a DOI in any common form (`doi:`, `https://doi.org/`, bare) to its
+/// lowercased bare form. Returns `None` if it doesn't look like a DOI.
+///
+/// The registrant must be `10.` followed by at least four digits, optionally
+/// with dot-separated numeric subdivisions (`10.1000.5/...`), matching the
+/// usual `10.\d{4,}/\S+` DOI pattern.
+pub fn normalize_doi(raw: &str) -> Option<String> {
+    let trimmed = raw.trim();
+    let lower = trimmed.to_lowercase();
+    let bare = [
+        "https://doi.org/",
+        "http://doi.org/",
+        "https://dx.doi.org/",
+        "http://dx.doi.org/",
+        "doi:",
+    ]
+    .iter()
+    .find_map(|prefix| lower.strip_prefix(prefix))
+    .unwrap_or(lower.as_str())
+    .trim();
+    let (registrant, suffix) = bare.split_once('/')?;
+    let valid_registrant = registrant.strip_prefix("10.").is_some_and(|rest| {
+        let mut parts = rest.split('.');
+        let code = parts.next().unwrap_or("");
+        code.len() >= 4
+            && code.chars().all(|c| c.is_ascii_digit())
+            && parts.all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+    });
+    if valid_registrant && !suffix.is_empty() && !suffix.chars().any(char::is_whitespace) {
+        Some(bare.to_string())
+    } else {
+        None
+    }
+}
+
+/// Split an author name into surname and given names. Accepts both
+/// "Smith, Jane Lee" and "Jane Lee Smith".
+fn name_parts(author: &str) -> (String, Vec<String>) {
+    let author = author.trim();
+    match author.split_once(',') {
+        Some((last, given)) => (
+            last.trim().to_string(),
+            given.split_whitespace().map(str::to_string).collect(),
+        ),
+        None => {
+            let mut words: Vec<String> = author.split_whitespace().map(str::to_string).collect();
+            let surname = words.pop().unwrap_or_default();
+            (surname, words)
+        }
+    }
+}
+
+/// Initials of the given names, e.g. `["Jane", "Lee"]` -> `J. L` with
+/// separator `". "` or `JL` with `""`.
+fn initials(given: &[String], separator: &str) -> String {
+    given
+        .iter()
+        .filter_map(|name| name.chars().find(|c| c.is_alphabetic()))
+        .map(|c| c.to_uppercase().to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Build a reference from tool parameters (everything except the key).
+fn reference_from_params(params: &serde_json::Value) -> Result<Reference, ToolError> {
+    let title = require_str(params, "title")?.trim().to_string();
+    if title.is_empty() {
+        return Err(ToolError::InvalidParameters(
+            "title must not be empty".to_string(),
+        ));
+    }
+    let doi = match params.get("doi").and_then(|v| v.as_str()) {
+        Some(raw) => Some(normalize_doi(raw).ok_or_else(|| {
+            ToolError::InvalidParameters(format!("'{}' is not a valid DOI", raw))
+        })?),
+        None => None,
+    };
+    Ok(Reference {
+        key: String::new(),
+        title,
+        authors: params
+            .get("authors")
+            .and_then(|v| v.as_array())
+            .map(|a| {
+                a.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        year: params
+            .get("year")
+            .and_then(|v| v.as_i64())
+            .and_then(|y| i32::try_from(y).ok()),
+        doi,
+        venue: optional_str(params, "venue"),
+        url: optional_str(params, "url"),
+    })
+}
+
+/// A trimmed, non-empty string parameter.
+fn optional_str(params: &serde_json::Value, name: &str) -> Option<String> {
+    params
+        .get(name)
+        .and_then(|v| v.as_str())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Parse the `style` parameter, if present.
+fn style_param(params: &serde_json::Value) -> Result<Option<CitationStyle>, ToolError> {
+    match params.get("style").and_then(|v| v.as_str()) {
+        Some(name) => CitationStyle::parse(name).map(Some).ok_or_else(|| {
+            ToolError::InvalidParameters(format!(
+                "unknown citation style '{}' (expected apa, mla, or vancouver)",
+                name
+            ))
+        }),
+        None => Ok(None),
+    }
+}
+
+/// Derive a citation key like `smith2024` from the first author and year.
+fn base_key(reference: &Reference) -> String {
+    let surname = reference
+        .authors
+        .first()
+        .map(|author| {
+            // "Smith, Jane" and "Jane Smith" both yield "smith".
+            match author.split_once(',') {
+                Some((last, _)) => last.to_string(),
+                None => author.split_whitespace().last().unwrap_or("").to_string(),
+            }
+        })
+        .unwrap_or_default()
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>();
+    let surname = if surname.is_empty() {
+        "ref".to_string()
+    } else {
+        surname
+    };
+    match reference.year {
+        Some(year) => format!("{}{}", surname, year),
+        None => surname,
+    }
+}
+
+/// Tool for managing a shared bibliography in the workspace.
+pub struct BibliographyTool {
+    resolver: Arc<dyn WorkspaceResolver>,
+}
+
+impl BibliographyTool {
+    /// Create a new bibliography tool with a workspace resolver.
+    pub fn new(resolver: Arc<dyn WorkspaceResolver>) -> Self {
+        Self { resolver }
+    }
+
+    /// Create from a fixed workspace (backward compatibility).
+    pub fn from_workspace(workspace: Arc<Workspace>) -> Self {
+        Self::new(Arc::new(FixedWorkspaceResolver::new(workspace)))
+    }
+
+    async fn load(workspace: &Workspace) -> Result<Library, ToolError> {
+        match workspace.read(LIBRARY_PATH).await {
+            Ok(doc) if doc.content.trim().is_empty() => Ok(Library::default()),
+            Ok(doc) => serde_json::from_str(&doc.content).map_err(|e| {
+                ToolError::ExecutionFailed(format!(
+                    "{} is not a valid library: {}",
+                    LIBRARY_PATH, e
+                ))
+            }),
+            Err(WorkspaceError::DocumentNotFound { .. }) => Ok(Library::default()),
+            Err(e) => Err(ToolError::ExecutionFailed(format!("Read failed: {}", e))),
+        }
+    }
+
+    /// Write the library back while holding the path lock taken before it
+    /// was loaded, so concurrent updates (including `memory_write`) can't
+    /// interleave with the read-modify-write.
+    async fn save(
+        workspace: &Workspace,
+        lock: &PathLock,
+        library: &Library,
+    ) -> Result<(), ToolError> {
+        let content = serde_json::to_string_pretty(library)
+            .map_err(|e| ToolError::ExecutionFailed(format!("Serialization failed: {}", e)))?;
+        workspace
+            .write_locked(lock, &content)
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Write failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn add(
+        &self,
+        workspace: &Workspace,
+        params: &serde_json::Value,
+    ) -> Result<serde_json::Value, ToolError> {
+        let mut reference = reference_from_params(params)?;
+
+        let lock = workspace.lock_for_update(LIBRARY_PATH).await;
+        let mut library = Self::load(workspace).await?;
+
+        if let Some(doi) = &reference.doi
+            && let Some(existing) = library
+                .references
+                .iter()
+                .find(|r| r.doi.as_ref() == Some(doi))
+        {
+            return Ok(serde_json::json!({
+                "key": existing.key,
+                "added": false,
+                "reason": "a reference with this DOI is already in the library",
+            }));
+        }
+
+        let requested = optional_str(params, "key");
+        let taken = |key: &str| library.references.iter().any(|r| r.key == key);
+        reference.key = match requested {
+            Some(key) if taken(&key) => {
+                return Err(ToolError::InvalidParameters(format!(
+                    "key '{}' is already in use",
+                    key
+                )));
+            }
+            Some(key) => key,
+            None => {
+                let base = base_key(&reference);
+                std::iter::once(base.clone())
+                    .chain(('a'..='z').map(|suffix| format!("{}{}", base, suffix)))
+                    .find(|key| !taken(key))
+                    .ok_or_else(|| {
+                        ToolError::InvalidParameters(format!(
+                            "too many references share the key '{}'; pass an explicit key",
+                            base
+                        ))
+                    })?
+            }
+        };
+
+        let key = reference.key.clone();
+        library.references.push(reference);
+        Self::save(workspace, &lock, &library).await?;
+
+        Ok(serde_json::json!({ "key": key, "added": true }))
+    }
+}
+
+#[async_trait]
+impl Tool for BibliographyTool {
+    fn name(&self) -> &str {
+        "bibliography"
+    }
+
+    fn category(&self) -> &str {
+        "memory"
+    }
+
+    fn description(&self) -> &str {
+        "Manage a shared reference library in the workspace. add stores a reference \
+         (deduplicated by DOI) and returns its citation key; get, list, and search look \
+         references up; cite returns a formatted citation for a key (optionally in APA, \
+         MLA, or Vancouver style); format_citation formats a reference passed inline \
+         without storing it. Cite library keys in documents instead of repeating full \
+         references."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "enum": ["add", "get", "list", "search", "cite", "format_citation"],
+                    "description": "The library operation to perform"
+                },
+                "key": {
+                    "type": "string",
+                    "description": "Citation key for get/cite; optional explicit key for add (default: first author surname + year)"
+                },
+                "title": {
+                    "type": "string",
+                    "description": "Reference title (required for add and format_citation)"
+                },
+                "authors": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Author names in order"
+                },
+                "year": {
+                    "type": "integer",
+                    "description": "Publication year"
+                },
+                "doi": {
+                    "type": "string",
+                    "description": "DOI in any common form (bare, doi:, or https://doi.org/ URL)"
+                },
+                "venue": {
+                    "type": "string",
+                    "description": "Journal, conference, or publisher"
+                },
+                "url": {
+                    "type": "string",
+                    "description": "Link to the reference when it has no DOI"
+                },
+                "style": {
+                    "type": "string",
+                    "enum": ["apa", "mla", "vancouver"],
+                    "description": "Citation style (required for format_citation; optional for cite, default is a plain citation)"
+                },
+                "query": {
+                    "type": "string",
+                    "description": "Case-insensitive text to match against key, title, authors, venue, and DOI (for search)"
+                }
+            },
+            "required": ["action"]
+        })
+    }
+
+    async fn execute(
+        &self,
+        params: serde_json::Value,
+        ctx: &JobContext,
+    ) -> Result<ToolOutput, ToolError> {
+        let start = std::time::Instant::now();
+
+        let action = require_str(&params, "action")?;
+        let workspace = self.resolver.resolve(&ctx.user_id).await;
+
+        let find = |library: &Library, key: &str| -> Result<Reference, ToolError> {
+            library
+                .references
+                .iter()
+                .find(|r| r.key == key)
+                .cloned()
+                .ok_or_else(|| {
+                    ToolError::InvalidParameters(format!("no reference with key '{}'", key))
+                })
+        };
+
+        let result = match action {
+            "add" => self.add(&workspace, &params).await?,
+            "get" => {
+                let key = require_str(&params, "key")?;
+                let library = Self::load(&workspace).await?;
+                serde_json::to_value(find(&library, key)?).map_err(|e| {
+                    ToolError::ExecutionFailed(format!("Serialization failed: {}", e))
+                })?
+            }
+            "list" => {
+                let mut library = Self::load(&workspace).await?;
+                library.references.sort_by(|a, b| a.key.cmp(&b.key));
+                let entries: Vec<serde_json::Value> = library
+                    .references
+                    .iter()
+                    .map(|r| serde_json::json!({ "key": r.key, "title": r.title, "year": r.year }))
+                    .collect();
+                serde_json::json!({ "references": entries, "count": entries.len() })
+            }
+            "search" => {
+                let query = require_str(&params, "query")?.to_lowercase();
+                let library = Self::load(&workspace).await?;
+                let matches: Vec<&Reference> = library
+                    .references
+                    .iter()
+                    .filter(|r| r.matches(&query))
+                    .collect();
+                serde_json::json!({ "references": matches, "count": matches.len() })
+            }
+            "cite" => {
+                let key = require_str(&params, "key")?;
+                let library = Self::load(&workspace).await?;
+                let reference = find(&library, key)?;
+                let citation = match style_param(&params)? {
+                    Some(style) => reference.format_citation(style),
+                    None => reference.citation(),
+                };
+                serde_json::json!({ "key": reference.key, "citation": citation })
+            }
+            "format_citation" => {
+                let style = style_param(&params)?.ok_or_else(|| {
+                    ToolError::InvalidParameters("missing 'style' parameter".to_string())
+                })?;
+                let reference = reference_from_params(&params)?;
+                serde_json::json!({ "citation": reference.format_citation(style) })
+            }
+            _ => {
+                return Err(ToolError::InvalidParameters(format!(
+                    "unknown action: {}",
+                    action
+                )));
+            }
+        };
+
+        Ok(ToolOutput::success(result, start.elapsed()))
+    }
+
+    fn requires_sanitization(&self) -> bool {
+        false // Internal memory
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_doi_forms_agree() {
+        let expected = Some("10.1000/xyz123".to_string());
+        assert_eq!(normalize_doi("10.1000/XYZ123"), expected);
+        assert_eq!(normalize_doi("doi:10.1000/xyz123"), expected);
+        assert_eq!(normalize_doi("https://doi.org/10.1000/xyz123"), expected);
+        assert_eq!(normalize_doi("not a doi"), None);
+        assert_eq!(normalize_doi("10.1000/"), None);
+        assert_eq!(
+            normalize_doi("10.1000.5/ABC"),
+            Some("10.1000.5/abc".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_doi_requires_four_digit_registrant() {
+        assert_eq!(normalize_doi("10.1/x"), None);
+        assert_eq!(normalize_doi("10.123/x"), None);
+        assert_eq!(normalize_doi("10../x"), None);
+        assert_eq!(normalize_doi("10.1000./x"), None);
+        assert_eq!(normalize_doi("10.12ab/x"), None);
+    }
+
+    #[test]
+    fn test_citation_format() {
+        let reference = Reference {
+            key: "smith2024".to_string(),
+            title: "Protein folding at scale.".to_string(),
+            authors: vec!["Jane Smith".to_string(), "Li Wei".to_string()],
+            year: Some(2024),
+            doi: Some("10.1000/xyz123".to_string()),
+            venue: Some("Nature".to_string()),
+            url: None,
+        };
+        assert_eq!(
+            reference.citation(),
+            "Jane Smith & Li Wei (2024). Protein folding at scale. Nature. https://doi.org/10.1000/xyz123"
+        );
+        assert_eq!(base_key(&reference), "smith2024");
+    }
+
+    fn article(authors: Vec<String>) -> Reference {
+        Reference {
+            key: String::new(),
+            title: "Protein folding at scale".to_string(),
+            authors,
+            year: Some(2024),
+            doi: Some("10.1000/xyz123".to_string()),
+            venue: Some("Nature".to_string()),
+            url: None,
+        }
+    }
+
+    #[test]
+    fn test_format_citation_three_authors() {
+        let reference = article(vec![
+            "Jane Smith".to_string(),
+            "Wei, Li".to_string(),
+            "Ana Maria Lopez".to_string(),
+        ]);
+        assert_eq!(
+            reference.format_citation(CitationStyle::Apa),
+            "Smith, J., Wei, L., & Lopez, A. M. (2024). Protein folding at scale. Nature. https://doi.org/10.1000/xyz123"
+        );
+        assert_eq!(
+            reference.format_citation(CitationStyle::Mla),
+            "Smith, Jane, et al. \"Protein folding at scale.\" Nature, 2024, https://doi.org/10.1000/xyz123."
+        );
+        assert_eq!(
+            article(vec!["Jane Smith".to_string(), "Wei, Li".to_string()])
+                .format_citation(CitationStyle::Mla),
+            "Smith, Jane, and Li Wei. \"Protein folding at scale.\" Nature, 2024, https://doi.org/10.1000/xyz123."
+        );
+        assert_eq!(
+            reference.format_citation(CitationStyle::Vancouver),
+            "Smith J, Wei L, Lopez AM. Protein folding at scale. Nature. 2024. doi:10.1000/xyz123"
+        );
+    }
+
+    #[test]
+    fn test_format_citation_truncates_long_author_lists() {
+        let authors: Vec<String> = (1..=25).map(|i| format!("Author{} Test", i)).collect();
+        let reference = article(authors);
+
+        let apa = reference.format_citation(CitationStyle::Apa);
+        assert!(apa.starts_with("Test, A., Test, A.,"));
+        assert_eq!(apa.matches("Test, A.").count(), APA_MAX_AUTHORS);
+        assert!(apa.contains(", et al. (2024)."));
+        assert!(!apa.contains('&'));
+
+        let vancouver = reference.format_citation(CitationStyle::Vancouver);
+        assert_eq!(vancouver.matches("Test A").count(), VANCOUVER_MAX_AUTHORS);
+        assert!(vancouver.contains("Test A, et al."));
+    }
+
+    #[test]
+    fn test_citation_style_parse() {
+        assert_eq!(CitationStyle::parse("APA"), Some(CitationStyle::Apa));
+        assert_eq!(
+            CitationStyle::parse("vancouver"),
+            Some(CitationStyle::Vancouver)
+        );
+        assert_eq!(CitationStyle::parse("chicago"), None);
+    }
+
+    #[cfg(feature = "libsql")]
+    mod libsql_tests {
+        use super::*;
+
+        #[tokio::test]
+        async fn test_add_dedups_by_doi_and_search_by_author() {
+            let (db, _dir) = crate::testing::test_db().await;
+            let ws = Arc::new(Workspace::new_with_db("bib_user", db));
+            let tool = BibliographyTool::from_workspace(ws);
+            let ctx = JobContext::with_user("bib_user", "test", "test");
+
+            let first = tool
+                .execute(
+                    serde_json::json!({
+                        "action": "add",
+                        "title": "Protein folding at scale",
+                        "authors": ["Jane Smith", "Li Wei"],
+                        "year": 2024,
+                        "doi": "10.1000/XYZ123"
+                    }),
+                    &ctx,
+                )
+                .await
+                .expect("first add");
+            assert_eq!(first.result["key"], "smith2024");
+            assert_eq!(first.result["added"], true);
+
+            let duplicate = tool
+                .execute(
+                    serde_json::json!({
+                        "action": "add",
+                        "title": "Protein Folding at Scale (preprint)",
+                        "authors": ["J. Smith"],
+                        "doi": "https://doi.org/10.1000/xyz123"
+                    }),
+                    &ctx,
+                )
+                .await
+                .expect("duplicate add");
+            assert_eq!(duplicate.result["key"], "smith2024");
+            assert_eq!(duplicate.result["added"], false);
+
+            let list = tool
+                .execute(serde_json::json!({"action": "list"}), &ctx)
+                .await
+                .expect("list");
+            assert_eq!(list.result["count"], 1);
+
+            let found = tool
+                .execute(
+                    serde_json::json!({"action": "search", "query": "wei"}),
+                    &ctx,
+                )
+                .await
+                .expect("search");
+            assert_eq!(found.result["count"], 1);
+            assert_eq!(found.result["references"][0]["key"], "smith2024");
+        }
+
+        #[tokio::test]
+        async fn test_concurrent_adds_to_one_library_are_kept() {
+            let (db, _dir) = crate::testing::test_db().await;
+            let ws = Arc::new(Workspace::new_with_db("bib_user", db));
+            let tool = BibliographyTool::from_workspace(ws);
+            let ctx = JobContext::with_user("bib_user", "test", "test");
+
+            let add = |doi: &'static str| {
+                tool.execute(
+                    serde_json::json!({"action": "add", "title": doi, "doi": doi}),
+                    &ctx,
+                )
+            };
+            let (first, second) = tokio::join!(add("10.1000/one"), add("10.1000/two"));
+            first.expect("first add");
+            second.expect("second add");
+
+            let list = tool
+                .execute(serde_json::json!({"action": "list"}), &ctx)
+                .await
+                .expect("list");
+            assert_eq!(list.result["count"], 2);
+        }
+    }
+}
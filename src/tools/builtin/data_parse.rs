@@ -0,0 +1,632 @@
+//! Data parsing tool for tabular, encoded, and prose inputs.
+
+use std::sync::LazyLock;
+
+use async_trait::async_trait;
+use base64::Engine;
+use regex::Regex;
+
+use crate::context::JobContext;
+use crate::tools::tool::{Tool, ToolError, ToolOutput, require_str};
+
+/// Delimiters considered by `detect_delimiter`, in tie-break order.
+const CANDIDATE_DELIMITERS: &[char] = &[',', '\t', ';', '|'];
+
+/// Number of leading lines sampled when detecting a delimiter.
+const DETECT_SAMPLE_LINES: usize = 10;
+
+/// Markup rewrites applied by [`strip_markup`], in order.
+static MARKUP_RULES: LazyLock<Vec<(Regex, &'static str)>> = LazyLock::new(|| {
+    [
+        // Fenced code block delimiters (the code itself is kept as text).
+        (r"(?m)^\s*(```|~~~).*$", ""),
+        // Images and links keep their visible text.
+        (r"!\[([^\]]*)\]\([^)]*\)", "$1"),
+        (r"\[([^\]]*)\]\([^)]*\)", "$1"),
+        // XML/HTML tags, e.g. JATS <italic> in PubMed abstracts. Only real
+        // tag syntax matches, so "p < 0.05 for n > 10" is left alone.
+        // Inline formatting tags vanish without a trace; other tags become
+        // a space so adjacent blocks don't run together.
+        (
+            r"(?i)</?(?:i|b|em|strong|u|sup|sub|span|a|italic|bold|sc|underline|code)(?:\s[^<>]*)?/?>",
+            "",
+        ),
+        (r"</?[A-Za-z][A-Za-z0-9:-]*(?:\s[^<>]*)?/?>", " "),
+        // Headings, blockquotes, and list markers at line start.
+        (r"(?m)^[ \t]*(#{1,6}|>+|[-*+]|\d+[.)])[ \t]+", ""),
+        // Emphasis and inline code.
+        (r"\*\*(.+?)\*\*", "$1"),
+        (r"__(.+?)__", "$1"),
+        (r"\*(\S(?:.*?\S)?)\*", "$1"),
+        (r"\b_(\S(?:.*?\S)?)_\b", "$1"),
+        (r"`([^`]*)`", "$1"),
+    ]
+    .into_iter()
+    .map(|(pattern, replacement)| {
+        (
+            Regex::new(pattern).expect("valid markup pattern"), // safety: constant pattern
+            replacement,
+        )
+    })
+    .collect()
+});
+
+/// Abbreviations whose trailing period does not end a sentence (lowercase,
+/// without the final period).
+const ABBREVIATIONS: &[&str] = &[
+    "al", "approx", "ca", "cf", "dr", "e.g", "eq", "eqs", "fig", "figs", "i.e", "mr", "mrs", "ms",
+    "no", "nos", "ref", "refs", "resp", "sp", "spp", "st", "vs",
+];
+
+/// Tool for turning raw data (CSV text, base64 blobs) into structured JSON.
+pub struct DataParseTool;
+
+#[async_trait]
+impl Tool for DataParseTool {
+    fn name(&self) -> &str {
+        "data_parse"
+    }
+
+    fn description(&self) -> &str {
+        "Parse raw data into structured JSON. csv_to_arrays turns CSV/TSV text into \
+         per-column arrays (numeric columns become numbers), detect_delimiter guesses \
+         the field separator, base64_decode decodes a base64 blob to text, and \
+         text_stats strips markdown/XML markup from prose and reports word and \
+         sentence counts plus a Flesch reading-ease score."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "operation": {
+                    "type": "string",
+                    "enum": ["csv_to_arrays", "detect_delimiter", "base64_decode", "text_stats"],
+                    "description": "The parse operation to perform"
+                },
+                "data": {
+                    "type": "string",
+                    "description": "Input text: CSV for csv_to_arrays/detect_delimiter, base64 for base64_decode, prose (markdown/XML allowed) for text_stats"
+                },
+                "delimiter": {
+                    "type": "string",
+                    "description": "Field separator for csv_to_arrays (single character or \"tab\"). Detected automatically when omitted."
+                },
+                "has_header": {
+                    "type": "boolean",
+                    "description": "Whether the first CSV row holds column names (default: true)"
+                }
+            },
+            "required": ["operation", "data"]
+        })
+    }
+
+    async fn execute(
+        &self,
+        params: serde_json::Value,
+        _ctx: &JobContext,
+    ) -> Result<ToolOutput, ToolError> {
+        let start = std::time::Instant::now();
+
+        let operation = require_str(&params, "operation")?;
+        let data = require_str(&params, "data")?;
+
+        let result = match operation {
+            "csv_to_arrays" => {
+                let delimiter = match params.get("delimiter").and_then(|v| v.as_str()) {
+                    Some(raw) => parse_delimiter(raw)?,
+                    None => detect_delimiter(data),
+                };
+                let has_header = params
+                    .get("has_header")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true);
+                csv_to_arrays(data, delimiter, has_header)?
+            }
+            "detect_delimiter" => {
+                serde_json::json!({ "delimiter": detect_delimiter(data).to_string() })
+            }
+            "base64_decode" => base64_decode(data)?,
+            "text_stats" => text_stats(data),
+            _ => {
+                return Err(ToolError::InvalidParameters(format!(
+                    "unknown operation: {}",
+                    operation
+                )));
+            }
+        };
+
+        Ok(ToolOutput::success(result, start.elapsed()))
+    }
+
+    fn requires_sanitization(&self) -> bool {
+        false // Internal tool, no external data
+    }
+
+    fn is_cacheable(&self) -> bool {
+        true // Pure function of its params
+    }
+}
+
+/// Parse a user-supplied delimiter: a single character, or "tab".
+fn parse_delimiter(raw: &str) -> Result<char, ToolError> {
+    if raw.eq_ignore_ascii_case("tab") || raw == "\\t" {
+        return Ok('\t');
+    }
+    let mut chars = raw.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c != '"' && c != '\n' && c != '\r' => Ok(c),
+        _ => Err(ToolError::InvalidParameters(format!(
+            "delimiter must be a single character other than a quote or newline, got {:?}",
+            raw
+        ))),
+    }
+}
+
+/// Count unquoted occurrences of `delimiter` in a single line.
+fn count_unquoted(line: &str, delimiter: char) -> usize {
+    let mut in_quotes = false;
+    let mut count = 0;
+    for c in line.chars() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        } else if c == delimiter && !in_quotes {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Guess the field delimiter from the first few non-empty lines.
+///
+/// Prefers a candidate that appears the same non-zero number of times on
+/// every sampled line; otherwise falls back to the most frequent candidate,
+/// and finally to a comma.
+fn detect_delimiter(data: &str) -> char {
+    let lines: Vec<&str> = data
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .take(DETECT_SAMPLE_LINES)
+        .collect();
+
+    let mut best_consistent: Option<(char, usize)> = None;
+    let mut best_total: Option<(char, usize)> = None;
+    for &candidate in CANDIDATE_DELIMITERS {
+        let counts: Vec<usize> = lines
+            .iter()
+            .map(|line| count_unquoted(line, candidate))
+            .collect();
+        let total: usize = counts.iter().sum();
+        if total == 0 {
+            continue;
+        }
+        if counts.iter().all(|&n| n == counts[0])
+            && best_consistent.is_none_or(|(_, n)| counts[0] > n)
+        {
+            best_consistent = Some((candidate, counts[0]));
+        }
+        if best_total.is_none_or(|(_, n)| total > n) {
+            best_total = Some((candidate, total));
+        }
+    }
+
+    best_consistent
+        .or(best_total)
+        .map(|(c, _)| c)
+        .unwrap_or(',')
+}
+
+/// Split CSV text into records, honoring quoted fields.
+///
+/// Quoted fields may contain the delimiter, newlines, and `""` escapes.
+/// Blank lines are skipped.
+fn parse_records(data: &str, delimiter: char) -> Result<Vec<Vec<String>>, ToolError> {
+    let mut records = Vec::new();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = data.chars().peekable();
+
+    fn end_record(records: &mut Vec<Vec<String>>, record: &mut Vec<String>, field: &mut String) {
+        record.push(std::mem::take(field));
+        let record = std::mem::take(record);
+        if !(record.len() == 1 && record[0].is_empty()) {
+            records.push(record);
+        }
+    }
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => end_record(&mut records, &mut record, &mut field),
+            c if c == delimiter => record.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(ToolError::InvalidParameters(
+            "unterminated quoted field in CSV data".to_string(),
+        ));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        end_record(&mut records, &mut record, &mut field);
+    }
+    Ok(records)
+}
+
+/// Parse CSV into per-column arrays.
+///
+/// A column whose non-empty cells all parse as finite numbers is returned as
+/// numbers (empty cells become `null`); any other column is returned as
+/// strings. Short rows are padded with empty cells.
+fn csv_to_arrays(
+    data: &str,
+    delimiter: char,
+    has_header: bool,
+) -> Result<serde_json::Value, ToolError> {
+    let mut records = parse_records(data, delimiter)?;
+    let header = if has_header && !records.is_empty() {
+        Some(records.remove(0))
+    } else {
+        None
+    };
+
+    let width = records
+        .iter()
+        .map(Vec::len)
+        .chain(header.iter().map(Vec::len))
+        .max()
+        .unwrap_or(0);
+
+    let columns: Vec<serde_json::Value> = (0..width)
+        .map(|i| {
+            let name = header
+                .as_ref()
+                .and_then(|h| h.get(i))
+                .map(|n| n.trim().to_string())
+                .filter(|n| !n.is_empty())
+                .unwrap_or_else(|| format!("column_{}", i + 1));
+            let cells: Vec<&str> = records
+                .iter()
+                .map(|r| r.get(i).map(|s| s.trim()).unwrap_or(""))
+                .collect();
+
+            let numbers: Option<Vec<Option<f64>>> = cells
+                .iter()
+                .map(|cell| {
+                    if cell.is_empty() {
+                        Some(None)
+                    } else {
+                        cell.parse::<f64>().ok().filter(|n| n.is_finite()).map(Some)
+                    }
+                })
+                .collect();
+            let is_numeric = numbers
+                .as_ref()
+                .is_some_and(|values| values.iter().any(Option::is_some));
+
+            let (kind, values) = match numbers {
+                Some(values) if is_numeric => ("number", serde_json::json!(values)),
+                _ => ("string", serde_json::json!(cells)),
+            };
+            serde_json::json!({ "name": name, "type": kind, "values": values })
+        })
+        .collect();
+
+    Ok(serde_json::json!({
+        "delimiter": delimiter.to_string(),
+        "rows": records.len(),
+        "columns": columns,
+    }))
+}
+
+/// Decode base64 (standard or URL-safe, padded or not) to text.
+fn base64_decode(data: &str) -> Result<serde_json::Value, ToolError> {
+    use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD};
+
+    let compact: String = data.chars().filter(|c| !c.is_whitespace()).collect();
+    let bytes = [STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD]
+        .iter()
+        .find_map(|engine| engine.decode(&compact).ok())
+        .ok_or_else(|| ToolError::InvalidParameters("invalid base64 data".to_string()))?;
+
+    let utf8 = std::str::from_utf8(&bytes).is_ok();
+    Ok(serde_json::json!({
+        "bytes": bytes.len(),
+        "utf8": utf8,
+        "text": String::from_utf8_lossy(&bytes),
+    }))
+}
+
+/// Reduce markdown or XML/HTML to plain prose.
+///
+/// Keeps link and image text, drops tags, heading/list/quote markers,
+/// emphasis, and code-fence lines, decodes the common entities, and
+/// collapses whitespace within each line.
+pub fn strip_markup(text: &str) -> String {
+    let mut plain = text.to_string();
+    for (re, replacement) in MARKUP_RULES.iter() {
+        plain = re.replace_all(&plain, *replacement).into_owned();
+    }
+    let plain = plain
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&");
+    plain
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Estimate English syllables by counting vowel groups, ignoring a silent
+/// trailing `e`. Always at least 1.
+fn count_syllables(word: &str) -> usize {
+    let letters: Vec<char> = word
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    let is_vowel = |c: char| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+    let mut groups = 0;
+    let mut prev_vowel = false;
+    for &c in &letters {
+        let vowel = is_vowel(c);
+        if vowel && !prev_vowel {
+            groups += 1;
+        }
+        prev_vowel = vowel;
+    }
+    if groups > 1 && letters.ends_with(&['e']) && !letters.ends_with(&['l', 'e']) {
+        groups -= 1;
+    }
+    groups.max(1)
+}
+
+/// Count sentences, treating a word ending in `.`, `!`, or `?` as a
+/// boundary unless it is an abbreviation or an initial ("E. coli", "et al.",
+/// "Fig. 2") or the next word starts in lowercase.
+fn count_sentences(text: &str) -> usize {
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let mut sentences = 0;
+    let mut open = false;
+    for (i, token) in tokens.iter().enumerate() {
+        if token.chars().any(char::is_alphanumeric) {
+            open = true;
+        }
+        let core = token.trim_end_matches(['"', '\'', ')', ']']);
+        if !open || !core.ends_with(['.', '!', '?']) {
+            continue;
+        }
+        let next_is_lower = tokens
+            .get(i + 1)
+            .and_then(|next| next.chars().find(|c| c.is_alphanumeric()))
+            .is_some_and(char::is_lowercase);
+        if next_is_lower || (core.ends_with('.') && !core.ends_with("..") && is_abbreviation(core))
+        {
+            continue;
+        }
+        sentences += 1;
+        open = false;
+    }
+    sentences + usize::from(open)
+}
+
+/// Whether `word` (ending in a single period) is an abbreviation or initial.
+fn is_abbreviation(word: &str) -> bool {
+    let stem = word
+        .trim_start_matches(['"', '\'', '(', '['])
+        .trim_end_matches('.');
+    let lower = stem.to_lowercase();
+    (stem.chars().count() == 1 && stem.chars().all(char::is_uppercase))
+        || ABBREVIATIONS.contains(&lower.as_str())
+}
+
+/// Word, sentence, and readability statistics for prose after markup removal.
+fn text_stats(data: &str) -> serde_json::Value {
+    let plain = strip_markup(data);
+    let words: Vec<&str> = plain
+        .split_whitespace()
+        .filter(|w| w.chars().any(char::is_alphanumeric))
+        .collect();
+    let sentences = count_sentences(&plain);
+    let syllables: usize = words.iter().map(|w| count_syllables(w)).sum();
+
+    let round2 = |x: f64| (x * 100.0).round() / 100.0;
+    let (avg_sentence_len, flesch) = if words.is_empty() || sentences == 0 {
+        (None, None)
+    } else {
+        let words_per_sentence = words.len() as f64 / sentences as f64;
+        let syllables_per_word = syllables as f64 / words.len() as f64;
+        (
+            Some(round2(words_per_sentence)),
+            Some(round2(
+                206.835 - 1.015 * words_per_sentence - 84.6 * syllables_per_word,
+            )),
+        )
+    };
+
+    serde_json::json!({
+        "plain_text": plain,
+        "word_count": words.len(),
+        "sentence_count": sentences,
+        "syllable_count": syllables,
+        "avg_sentence_length": avg_sentence_len,
+        "flesch_reading_ease": flesch,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn run(params: serde_json::Value) -> Result<serde_json::Value, ToolError> {
+        let ctx = JobContext::default();
+        DataParseTool
+            .execute(params, &ctx)
+            .await
+            .map(|output| output.result)
+    }
+
+    #[tokio::test]
+    async fn test_csv_with_header_yields_numeric_columns() {
+        let result = run(serde_json::json!({
+            "operation": "csv_to_arrays",
+            "data": "time,temp\n0,20.5\n1,21\n2,21.5\n"
+        }))
+        .await
+        .unwrap();
+
+        assert_eq!(result["rows"], 3);
+        let columns = result["columns"].as_array().unwrap();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0]["name"], "time");
+        assert_eq!(columns[0]["type"], "number");
+        assert_eq!(columns[0]["values"], serde_json::json!([0.0, 1.0, 2.0]));
+        assert_eq!(columns[1]["name"], "temp");
+        assert_eq!(columns[1]["values"], serde_json::json!([20.5, 21.0, 21.5]));
+    }
+
+    #[tokio::test]
+    async fn test_csv_quoted_fields_and_mixed_types() {
+        let result = run(serde_json::json!({
+            "operation": "csv_to_arrays",
+            "data": "label,value\r\n\"a, b\",1\r\n\"say \"\"hi\"\"\",\r\n",
+            "delimiter": ","
+        }))
+        .await
+        .unwrap();
+
+        let columns = result["columns"].as_array().unwrap();
+        assert_eq!(columns[0]["type"], "string");
+        assert_eq!(
+            columns[0]["values"],
+            serde_json::json!(["a, b", "say \"hi\""])
+        );
+        // Empty cells in a numeric column become null.
+        assert_eq!(columns[1]["type"], "number");
+        assert_eq!(columns[1]["values"], serde_json::json!([1.0, null]));
+    }
+
+    #[tokio::test]
+    async fn test_csv_unterminated_quote_is_rejected() {
+        let result = run(serde_json::json!({
+            "operation": "csv_to_arrays",
+            "data": "a,b\n\"open,1\n"
+        }))
+        .await;
+        assert!(matches!(result, Err(ToolError::InvalidParameters(_))));
+    }
+
+    #[test]
+    fn test_detect_delimiter() {
+        assert_eq!(detect_delimiter("a;b;c\n1;2;3\n"), ';');
+        assert_eq!(detect_delimiter("a\tb\n1\t2\n"), '\t');
+        // Commas inside quotes don't count.
+        assert_eq!(detect_delimiter("\"x,y\"|z\n\"1,2\"|3\n"), '|');
+        assert_eq!(detect_delimiter("single column\n"), ',');
+    }
+
+    #[tokio::test]
+    async fn test_base64_decode() {
+        let result = run(serde_json::json!({
+            "operation": "base64_decode",
+            "data": "aGVsbG8g\nd29ybGQ="
+        }))
+        .await
+        .unwrap();
+        assert_eq!(result["text"], "hello world");
+        assert_eq!(result["bytes"], 11);
+        assert_eq!(result["utf8"], true);
+
+        let invalid = run(serde_json::json!({
+            "operation": "base64_decode",
+            "data": "not base64!"
+        }))
+        .await;
+        assert!(matches!(invalid, Err(ToolError::InvalidParameters(_))));
+    }
+
+    #[test]
+    fn test_strip_markup() {
+        let plain = strip_markup(
+            "## Abstract\n\n<p>We measured **cell** growth in [HeLa](https://x.org) \
+             cells &amp; <i>E. coli</i>.</p>\n- `n = 12`\n",
+        );
+        assert_eq!(
+            plain,
+            "Abstract\nWe measured cell growth in HeLa cells & E. coli.\nn = 12"
+        );
+        // Underscores inside identifiers survive.
+        assert_eq!(strip_markup("snake_case_name"), "snake_case_name");
+        // Block tags still separate words.
+        assert_eq!(strip_markup("one<br/>two</p><p>three"), "one two three");
+    }
+
+    #[test]
+    fn test_strip_markup_keeps_inequalities() {
+        assert_eq!(
+            strip_markup("Significant (p < 0.05) for n > 10 and x<y."),
+            "Significant (p < 0.05) for n > 10 and x<y."
+        );
+        assert_eq!(
+            strip_markup("p &lt; 0.01 in <sup>3</sup>H-labelled cells"),
+            "p < 0.01 in 3H-labelled cells"
+        );
+    }
+
+    #[test]
+    fn test_sentence_count_skips_abbreviations() {
+        assert_eq!(
+            count_sentences("E. coli grew well. Growth was slow (Smith et al. 2020)."),
+            2
+        );
+        assert_eq!(
+            count_sentences("See Fig. 2 and e.g. Table 1. Results held! Why?"),
+            3
+        );
+        assert_eq!(count_sentences("No terminator here"), 1);
+        assert_eq!(count_sentences(""), 0);
+    }
+
+    #[tokio::test]
+    async fn test_text_stats_counts_words_and_sentences() {
+        let result = run(serde_json::json!({
+            "operation": "text_stats",
+            "data": "<p>The **cells** grew fast. They doubled in *two* days!</p>\n\nWhy?"
+        }))
+        .await
+        .unwrap();
+
+        assert_eq!(result["word_count"], 10);
+        assert_eq!(result["sentence_count"], 3);
+        assert_eq!(result["avg_sentence_length"], 3.33);
+        assert!(result["flesch_reading_ease"].as_f64().unwrap() > 80.0);
+
+        let empty = run(serde_json::json!({"operation": "text_stats", "data": "  "}))
+            .await
+            .unwrap();
+        assert_eq!(empty["word_count"], 0);
+        assert!(empty["flesch_reading_ease"].is_null());
+    }
+}
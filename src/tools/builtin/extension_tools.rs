@@ -29,6 +29,10 @@ impl Tool for ToolSearchTool {
         "tool_search"
     }
 
+    fn category(&self) -> &str {
+        "extensions"
+    }
+
     fn description(&self) -> &str {
         "Search for available extensions to add new capabilities. Extensions include \
          channels (Telegram, Slack, Discord — connect messaging platforms so IronClaw can \
@@ -103,6 +107,10 @@ impl Tool for ToolInstallTool {
         "tool_install"
     }
 
+    fn category(&self) -> &str {
+        "extensions"
+    }
+
     fn description(&self) -> &str {
         "Install an extension (channel, tool, or MCP server). \
          Use the name from tool_search results, or provide an explicit URL."
@@ -166,6 +174,10 @@ impl Tool for ToolInstallTool {
     fn requires_approval(&self, _params: &serde_json::Value) -> ApprovalRequirement {
         ApprovalRequirement::UnlessAutoApproved
     }
+
+    fn requires_network(&self) -> bool {
+        true
+    }
 }
 
 // ── tool_auth ────────────────────────────────────────────────────────────
@@ -186,6 +198,10 @@ impl Tool for ToolAuthTool {
         "tool_auth"
     }
 
+    fn category(&self) -> &str {
+        "extensions"
+    }
+
     fn description(&self) -> &str {
         "Initiate authentication for an extension. For OAuth, returns a URL. \
          For manual auth, returns instructions. The user provides their token \
@@ -287,6 +303,10 @@ impl Tool for ToolActivateTool {
         "tool_activate"
     }
 
+    fn category(&self) -> &str {
+        "extensions"
+    }
+
     fn description(&self) -> &str {
         "Activate an installed extension — starts channels, loads tools, or connects to MCP servers."
     }
@@ -381,6 +401,10 @@ impl Tool for ToolListTool {
         "tool_list"
     }
 
+    fn category(&self) -> &str {
+        "extensions"
+    }
+
     fn description(&self) -> &str {
         "List extensions with their authentication and activation status. \
          Set include_available:true to also show registry entries not yet installed."
@@ -459,6 +483,10 @@ impl Tool for ToolRemoveTool {
         "tool_remove"
     }
 
+    fn category(&self) -> &str {
+        "extensions"
+    }
+
     fn description(&self) -> &str {
         "Permanently remove an installed extension (channel, tool, or MCP server) from disk. \
          This action cannot be undone — the WASM binary and configuration files will be deleted."
@@ -523,6 +551,10 @@ impl Tool for ToolUpgradeTool {
         "tool_upgrade"
     }
 
+    fn category(&self) -> &str {
+        "extensions"
+    }
+
     fn description(&self) -> &str {
         "Upgrade installed WASM extensions (channels and tools) to match the current \
          host WIT version. If name is omitted, checks and upgrades all installed WASM \
@@ -565,6 +597,10 @@ impl Tool for ToolUpgradeTool {
     fn requires_approval(&self, _params: &serde_json::Value) -> ApprovalRequirement {
         ApprovalRequirement::UnlessAutoApproved
     }
+
+    fn requires_network(&self) -> bool {
+        true
+    }
 }
 
 // ── extension_info ────────────────────────────────────────────────────
@@ -585,6 +621,10 @@ impl Tool for ExtensionInfoTool {
         "extension_info"
     }
 
+    fn category(&self) -> &str {
+        "extensions"
+    }
+
     fn description(&self) -> &str {
         "Show detailed information about an installed extension, including version \
          and WIT version compatibility."
@@ -13,7 +13,7 @@ use tokio::fs;
 use crate::context::JobContext;
 use crate::tools::builtin::path_utils::validate_path;
 use crate::tools::tool::{
-    ApprovalRequirement, Tool, ToolDomain, ToolError, ToolOutput, require_str,
+    ApprovalRequirement, Tool, ToolDomain, ToolError, ToolExample, ToolOutput, require_str,
 };
 use crate::workspace::paths as ws_paths;
 
@@ -76,6 +76,10 @@ impl Tool for ReadFileTool {
         "read_file"
     }
 
+    fn category(&self) -> &str {
+        "development"
+    }
+
     fn description(&self) -> &str {
         "Read a file from the LOCAL FILESYSTEM. NOT for workspace memory paths \
          (use memory_read for those). Returns file content as text. \
@@ -177,6 +181,31 @@ impl Tool for ReadFileTool {
     fn domain(&self) -> ToolDomain {
         ToolDomain::Container
     }
+
+    fn examples(&self) -> Vec<ToolExample> {
+        vec![
+            ToolExample::new(
+                "Read a whole file",
+                serde_json::json!({"path": "src/main.rs"}),
+                serde_json::json!({
+                    "content": "     1│ fn main() {\n     2│     println!(\"hi\");\n     3│ }",
+                    "total_lines": 3,
+                    "lines_shown": 3,
+                    "path": "/project/src/main.rs"
+                }),
+            ),
+            ToolExample::new(
+                "Read lines 120-139 of a large file",
+                serde_json::json!({"path": "src/lib.rs", "offset": 120, "limit": 20}),
+                serde_json::json!({
+                    "content": "   120│ pub mod config;\n...",
+                    "total_lines": 480,
+                    "lines_shown": 20,
+                    "path": "/project/src/lib.rs"
+                }),
+            ),
+        ]
+    }
 }
 
 /// Write file contents tool.
@@ -202,6 +231,10 @@ impl Tool for WriteFileTool {
         "write_file"
     }
 
+    fn category(&self) -> &str {
+        "development"
+    }
+
     fn description(&self) -> &str {
         "Write content to a file on the LOCAL FILESYSTEM. NOT for workspace memory \
          (use memory_write for that). Creates the file if it doesn't exist, overwrites if it does. \
@@ -292,6 +325,18 @@ impl Tool for WriteFileTool {
     fn rate_limit_config(&self) -> Option<crate::tools::tool::ToolRateLimitConfig> {
         Some(crate::tools::tool::ToolRateLimitConfig::new(20, 200))
     }
+
+    fn examples(&self) -> Vec<ToolExample> {
+        vec![ToolExample::new(
+            "Create or overwrite a file",
+            serde_json::json!({"path": "notes/todo.txt", "content": "- fix tests\n"}),
+            serde_json::json!({
+                "path": "/project/notes/todo.txt",
+                "bytes_written": 12,
+                "success": true
+            }),
+        )]
+    }
 }
 
 /// List directory contents tool.
@@ -317,6 +362,10 @@ impl Tool for ListDirTool {
         "list_dir"
     }
 
+    fn category(&self) -> &str {
+        "development"
+    }
+
     fn description(&self) -> &str {
         "List contents of a directory on the LOCAL FILESYSTEM. NOT for workspace memory \
          (use memory_tree for that). Shows files and subdirectories with their sizes."
@@ -400,6 +449,31 @@ impl Tool for ListDirTool {
     fn domain(&self) -> ToolDomain {
         ToolDomain::Container
     }
+
+    fn examples(&self) -> Vec<ToolExample> {
+        vec![
+            ToolExample::new(
+                "List the current directory",
+                serde_json::json!({}),
+                serde_json::json!({
+                    "path": "/project",
+                    "entries": ["src/", "Cargo.toml (1.2KB)", "README.md (812B)"],
+                    "count": 3,
+                    "truncated": false
+                }),
+            ),
+            ToolExample::new(
+                "List a directory two levels deep",
+                serde_json::json!({"path": "src", "recursive": true, "max_depth": 2}),
+                serde_json::json!({
+                    "path": "/project/src",
+                    "entries": ["tools/", "main.rs (96B)", "tools/mod.rs (2.4KB)"],
+                    "count": 3,
+                    "truncated": false
+                }),
+            ),
+        ]
+    }
 }
 
 /// Recursively list directory contents.
@@ -510,6 +584,10 @@ impl Tool for ApplyPatchTool {
         "apply_patch"
     }
 
+    fn category(&self) -> &str {
+        "development"
+    }
+
     fn description(&self) -> &str {
         "Apply targeted edits to a file using search/replace. Finds the exact 'old_string' \
          and replaces it with 'new_string'. Use for surgical code changes without rewriting entire files. \
@@ -617,6 +695,38 @@ impl Tool for ApplyPatchTool {
     fn rate_limit_config(&self) -> Option<crate::tools::tool::ToolRateLimitConfig> {
         Some(crate::tools::tool::ToolRateLimitConfig::new(20, 200))
     }
+
+    fn examples(&self) -> Vec<ToolExample> {
+        vec![
+            ToolExample::new(
+                "Replace the first occurrence of a snippet",
+                serde_json::json!({
+                    "path": "src/main.rs",
+                    "old_string": "println!(\"old\");",
+                    "new_string": "println!(\"new\");"
+                }),
+                serde_json::json!({
+                    "path": "/project/src/main.rs",
+                    "replacements": 1,
+                    "success": true
+                }),
+            ),
+            ToolExample::new(
+                "Rename an identifier everywhere in a file",
+                serde_json::json!({
+                    "path": "src/config.rs",
+                    "old_string": "max_retries",
+                    "new_string": "retry_limit",
+                    "replace_all": true
+                }),
+                serde_json::json!({
+                    "path": "/project/src/config.rs",
+                    "replacements": 4,
+                    "success": true
+                }),
+            ),
+        ]
+    }
 }
 
 #[cfg(test)]
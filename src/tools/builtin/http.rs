@@ -12,7 +12,9 @@ use reqwest::Client;
 use crate::context::JobContext;
 use crate::safety::LeakDetector;
 use crate::secrets::SecretsStore;
-use crate::tools::tool::{ApprovalRequirement, Tool, ToolError, ToolOutput, require_str};
+use crate::tools::tool::{
+    ApprovalRequirement, Tool, ToolError, ToolExample, ToolOutput, require_str,
+};
 use crate::tools::wasm::{InjectedCredentials, SharedCredentialRegistry, inject_credential};
 
 #[cfg(feature = "html-to-markdown")]
@@ -31,6 +33,9 @@ const MAX_RESPONSE_SIZE: usize = 5 * 1024 * 1024;
 /// in memory for LLM context. Matches the WASM attachment size cap.
 const MAX_SAVE_TO_SIZE: usize = 50 * 1024 * 1024;
 
+/// How much of a 429 response body is kept in the rate-limit error.
+const RATE_LIMIT_BODY_PREVIEW: usize = 1024;
+
 /// Default request timeout when the caller does not provide one.
 const DEFAULT_TIMEOUT_SECS: u64 = 30;
 
@@ -386,6 +391,10 @@ impl Tool for HttpTool {
         "http"
     }
 
+    fn category(&self) -> &str {
+        "network"
+    }
+
     fn description(&self) -> &str {
         "Make HTTP requests to external APIs. Supports GET, POST, PUT, DELETE methods. \
          Use save_to to download binary files (images, PDFs, etc.) to a local path, \
@@ -573,6 +582,9 @@ impl Tool for HttpTool {
             && let Some(recorded) = interceptor.before_request(&intercept_req).await
         {
             let headers: HashMap<String, String> = recorded.headers.iter().cloned().collect();
+            if recorded.status == 429 {
+                return Err(rate_limited_error(&headers, &recorded.body));
+            }
             let body: serde_json::Value = serde_json::from_str(&recorded.body)
                 .unwrap_or_else(|_| serde_json::Value::String(recorded.body.clone()));
             let result = serde_json::json!({
@@ -707,6 +719,27 @@ impl Tool for HttpTool {
             .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.to_string(), v.to_string())))
             .collect();
 
+        if status == 429 {
+            tracing::debug!(url = %parsed_url, "HTTP tool request was rate limited");
+            let body = read_body_preview(response, RATE_LIMIT_BODY_PREVIEW).await;
+            if let Some(ref interceptor) = ctx.http_interceptor {
+                interceptor
+                    .after_response(
+                        &intercept_req,
+                        &crate::llm::recording::HttpExchangeResponse {
+                            status,
+                            headers: headers
+                                .iter()
+                                .map(|(k, v)| (k.clone(), v.clone()))
+                                .collect(),
+                            body: body.clone(),
+                        },
+                    )
+                    .await;
+            }
+            return Err(rate_limited_error(&headers, &body));
+        }
+
         // Use a larger size limit when saving to disk (file downloads)
         let saving_to_disk = save_to.is_some();
         let max_size = if saving_to_disk {
@@ -829,6 +862,10 @@ impl Tool for HttpTool {
         true // External data always needs sanitization
     }
 
+    fn requires_network(&self) -> bool {
+        true
+    }
+
     fn requires_approval(&self, params: &serde_json::Value) -> ApprovalRequirement {
         let has_credentials = crate::safety::params_contain_manual_credentials(params)
             || (self.credential_registry.as_ref().is_some_and(|registry| {
@@ -852,6 +889,84 @@ impl Tool for HttpTool {
     fn rate_limit_config(&self) -> Option<crate::tools::tool::ToolRateLimitConfig> {
         Some(crate::tools::tool::ToolRateLimitConfig::new(30, 500))
     }
+
+    fn examples(&self) -> Vec<ToolExample> {
+        vec![
+            ToolExample::new(
+                "Fetch JSON from an API",
+                serde_json::json!({"url": "https://api.example.com/v1/status"}),
+                serde_json::json!({
+                    "status": 200,
+                    "headers": {"content-type": "application/json"},
+                    "body": {"ok": true}
+                }),
+            ),
+            ToolExample::new(
+                "POST a JSON body with a custom header",
+                serde_json::json!({
+                    "method": "POST",
+                    "url": "https://api.example.com/v1/items",
+                    "headers": [{"name": "Accept", "value": "application/json"}],
+                    "body": {"name": "first"}
+                }),
+                serde_json::json!({
+                    "status": 201,
+                    "headers": {"content-type": "application/json"},
+                    "body": {"id": 1, "name": "first"}
+                }),
+            ),
+            ToolExample::new(
+                "Download a binary file",
+                serde_json::json!({
+                    "url": "https://example.com/report.pdf",
+                    "save_to": "/tmp/report.pdf"
+                }),
+                serde_json::json!({
+                    "status": 200,
+                    "saved_to": "/tmp/report.pdf",
+                    "size_bytes": 48213,
+                    "headers": {"content-type": "application/pdf"}
+                }),
+            ),
+        ]
+    }
+}
+
+/// Read at most `limit` bytes of a response body, lossily decoded. Errors
+/// just end the preview early.
+async fn read_body_preview(response: reqwest::Response, limit: usize) -> String {
+    let mut body = Vec::new();
+    let mut stream = response.bytes_stream();
+    while body.len() < limit {
+        match StreamExt::next(&mut stream).await {
+            Some(Ok(chunk)) => body.extend_from_slice(&chunk),
+            _ => break,
+        }
+    }
+    String::from_utf8_lossy(&body).into_owned()
+}
+
+/// Map a 429 response to `ToolError::RateLimited`, carrying the `Retry-After`
+/// hint so the caller can back off instead of hammering the server, and the
+/// start of the response body, which usually explains the limit.
+fn rate_limited_error(headers: &HashMap<String, String>, body: &str) -> ToolError {
+    let retry_after = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+        .and_then(|(_, value)| crate::llm::retry::parse_retry_after_value(value));
+    let body = body.trim();
+    let message = (!body.is_empty()).then(|| {
+        let end = crate::util::floor_char_boundary(body, RATE_LIMIT_BODY_PREVIEW);
+        if end < body.len() {
+            format!("{}...", &body[..end])
+        } else {
+            body.to_string()
+        }
+    });
+    ToolError::RateLimited {
+        retry_after,
+        message,
+    }
 }
 
 #[cfg(test)]
@@ -866,6 +981,113 @@ mod tests {
         assert_eq!(schema["properties"]["headers"]["type"], "array");
     }
 
+    #[test]
+    fn test_requires_network_is_independent_of_approval() {
+        let tool = HttpTool::new();
+        assert!(tool.requires_network());
+        assert_eq!(
+            tool.requires_approval(&serde_json::json!({"url": "https://example.com"})),
+            ApprovalRequirement::Never
+        );
+        assert_eq!(
+            tool.requires_approval(
+                &serde_json::json!({"method": "POST", "url": "https://example.com"})
+            ),
+            ApprovalRequirement::UnlessAutoApproved
+        );
+    }
+
+    #[test]
+    fn test_rate_limited_error_parses_retry_after() {
+        let headers = HashMap::from([("Retry-After".to_string(), "7".to_string())]);
+        match rate_limited_error(&headers, "") {
+            ToolError::RateLimited {
+                retry_after,
+                message,
+            } => {
+                assert_eq!(retry_after, Some(Duration::from_secs(7)));
+                assert_eq!(message, None);
+            }
+            other => panic!("expected RateLimited, got {other:?}"),
+        }
+
+        assert!(matches!(
+            rate_limited_error(&HashMap::new(), "  "),
+            ToolError::RateLimited {
+                retry_after: None,
+                message: None
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_replayed_429_returns_rate_limited_error() {
+        use crate::llm::recording::{
+            HttpExchange, HttpExchangeRequest, HttpExchangeResponse, ReplayingHttpInterceptor,
+        };
+
+        // An IP literal keeps DNS resolution offline; the interceptor answers
+        // before any connection is made.
+        let url = "https://93.184.216.34/v1/search";
+        let body = format!(
+            "quota exceeded: {}",
+            "x".repeat(2 * RATE_LIMIT_BODY_PREVIEW)
+        );
+        let interceptor = ReplayingHttpInterceptor::new(vec![HttpExchange {
+            request: HttpExchangeRequest {
+                method: "GET".to_string(),
+                url: url.to_string(),
+                headers: Vec::new(),
+                body: None,
+            },
+            response: HttpExchangeResponse {
+                status: 429,
+                headers: vec![("Retry-After".to_string(), "12".to_string())],
+                body,
+            },
+        }]);
+        let mut ctx = JobContext::default();
+        ctx.http_interceptor = Some(Arc::new(interceptor));
+
+        let err = HttpTool::new()
+            .execute(serde_json::json!({"url": url}), &ctx)
+            .await
+            .expect_err("429 should be an error, not a ToolOutput");
+        match err {
+            ToolError::RateLimited {
+                retry_after,
+                message: Some(message),
+            } => {
+                assert_eq!(retry_after, Some(Duration::from_secs(12)));
+                assert!(message.starts_with("quota exceeded: xxx"));
+                assert!(message.ends_with("..."));
+                assert_eq!(message.len(), RATE_LIMIT_BODY_PREVIEW + 3);
+            }
+            other => panic!("expected RateLimited with a message, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_rate_limited_error_keeps_truncated_body() {
+        let err = rate_limited_error(&HashMap::new(), "{\"error\": \"daily quota exceeded\"}\n");
+        assert_eq!(
+            err.to_string(),
+            "Rate limited: {\"error\": \"daily quota exceeded\"}"
+        );
+
+        let long = "é".repeat(RATE_LIMIT_BODY_PREVIEW);
+        match rate_limited_error(&HashMap::new(), &long) {
+            ToolError::RateLimited {
+                message: Some(message),
+                ..
+            } => {
+                assert!(message.ends_with("..."));
+                assert!(message.len() <= RATE_LIMIT_BODY_PREVIEW + 3);
+            }
+            other => panic!("expected RateLimited with a message, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_validate_url_rejects_http() {
         let err = validate_url("http://example.com").unwrap_err();
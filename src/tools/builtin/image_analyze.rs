@@ -64,6 +64,10 @@ impl Tool for ImageAnalyzeTool {
         "image_analyze"
     }
 
+    fn category(&self) -> &str {
+        "media"
+    }
+
     fn description(&self) -> &str {
         "Analyze an image using a vision-capable AI model. Provide a workspace path to the image and an optional analysis question."
     }
@@ -90,6 +94,10 @@ impl Tool for ImageAnalyzeTool {
         true
     }
 
+    fn requires_network(&self) -> bool {
+        true
+    }
+
     async fn execute(
         &self,
         params: serde_json::Value,
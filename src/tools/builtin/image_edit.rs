@@ -63,6 +63,10 @@ impl Tool for ImageEditTool {
         "image_edit"
     }
 
+    fn category(&self) -> &str {
+        "media"
+    }
+
     fn description(&self) -> &str {
         "Edit an existing image using an AI model. Provide the workspace path to the source image and a text prompt describing the desired edits."
     }
@@ -89,6 +93,10 @@ impl Tool for ImageEditTool {
         false
     }
 
+    fn requires_network(&self) -> bool {
+        true
+    }
+
     async fn execute(
         &self,
         params: serde_json::Value,
@@ -62,6 +62,10 @@ impl Tool for ImageGenerateTool {
         "image_generate"
     }
 
+    fn category(&self) -> &str {
+        "media"
+    }
+
     fn description(&self) -> &str {
         "Generate an image from a text prompt using an AI image generation model (e.g., FLUX). Returns the generated image data."
     }
@@ -90,6 +94,10 @@ impl Tool for ImageGenerateTool {
         false
     }
 
+    fn requires_network(&self) -> bool {
+        true
+    }
+
     async fn execute(
         &self,
         params: serde_json::Value,
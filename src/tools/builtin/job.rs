@@ -800,6 +800,10 @@ impl Tool for CreateJobTool {
         "create_job"
     }
 
+    fn category(&self) -> &str {
+        "jobs"
+    }
+
     fn description(&self) -> &str {
         if self.sandbox_enabled() {
             "Create and execute a job. The job runs in a sandboxed Docker container with its own \
@@ -940,6 +944,10 @@ impl Tool for ListJobsTool {
         "list_jobs"
     }
 
+    fn category(&self) -> &str {
+        "jobs"
+    }
+
     fn description(&self) -> &str {
         "List all jobs or filter by status. Shows job IDs, titles, and current status."
     }
@@ -1033,6 +1041,10 @@ impl Tool for JobStatusTool {
         "job_status"
     }
 
+    fn category(&self) -> &str {
+        "jobs"
+    }
+
     fn description(&self) -> &str {
         "Check the status and details of a specific job by its ID."
     }
@@ -1134,6 +1146,10 @@ impl Tool for CancelJobTool {
         "cancel_job"
     }
 
+    fn category(&self) -> &str {
+        "jobs"
+    }
+
     fn description(&self) -> &str {
         "Cancel a running or pending job. The job will be marked as cancelled and stopped."
     }
@@ -1269,6 +1285,10 @@ impl Tool for JobEventsTool {
         "job_events"
     }
 
+    fn category(&self) -> &str {
+        "jobs"
+    }
+
     fn description(&self) -> &str {
         "Read the event log for a sandbox job. Shows messages, tool calls, results, \
          and status changes from the container. Use this to check what Claude Code \
@@ -1402,6 +1422,10 @@ impl Tool for JobPromptTool {
         "job_prompt"
     }
 
+    fn category(&self) -> &str {
+        "jobs"
+    }
+
     fn description(&self) -> &str {
         "Send a follow-up prompt to a running Claude Code sandbox job. The prompt is \
          queued and delivered on the next poll cycle. Use this to give the sub-agent \
@@ -3,7 +3,7 @@
 use async_trait::async_trait;
 
 use crate::context::JobContext;
-use crate::tools::tool::{Tool, ToolError, ToolOutput, require_param, require_str};
+use crate::tools::tool::{Tool, ToolError, ToolExample, ToolOutput, require_param, require_str};
 
 /// Tool for JSON manipulation (parse, query, transform).
 pub struct JsonTool;
@@ -132,6 +132,39 @@ impl Tool for JsonTool {
     fn requires_sanitization(&self) -> bool {
         false // Internal tool, no external data
     }
+
+    fn is_cacheable(&self) -> bool {
+        true // Pure function of its params
+    }
+
+    fn examples(&self) -> Vec<ToolExample> {
+        vec![
+            ToolExample::new(
+                "Parse a JSON string into a value",
+                serde_json::json!({"operation": "parse", "data": "{\"a\": 1}"}),
+                serde_json::json!({"a": 1}),
+            ),
+            ToolExample::new(
+                "Extract a nested field with a path",
+                serde_json::json!({
+                    "operation": "query",
+                    "data": {"items": [{"name": "first"}]},
+                    "path": "items[0].name"
+                }),
+                serde_json::json!("first"),
+            ),
+            ToolExample::new(
+                "Pretty-print a value",
+                serde_json::json!({"operation": "stringify", "data": {"a": 1}}),
+                serde_json::json!("{\n  \"a\": 1\n}"),
+            ),
+            ToolExample::new(
+                "Check whether a string is valid JSON",
+                serde_json::json!({"operation": "validate", "data": "{not json}"}),
+                serde_json::json!({"valid": false}),
+            ),
+        ]
+    }
 }
 
 fn parse_json_input(data: &serde_json::Value) -> Result<serde_json::Value, ToolError> {
@@ -207,6 +240,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_examples_cover_every_operation() {
+        let tool = JsonTool;
+        let schema = tool.parameters_schema();
+        let operations = schema["properties"]["operation"]["enum"]
+            .as_array()
+            .unwrap();
+        let examples = tool.examples();
+
+        for op in operations {
+            let example = examples
+                .iter()
+                .find(|e| e.params["operation"] == *op)
+                .unwrap_or_else(|| panic!("no example for operation {op}"));
+            for required in schema["required"].as_array().unwrap() {
+                let key = required.as_str().unwrap();
+                assert!(example.params.get(key).is_some(), "missing {key}");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_examples_produce_their_documented_output() {
+        let tool = JsonTool;
+        let ctx = crate::context::JobContext::default();
+        for example in tool.examples() {
+            let output = tool.execute(example.params.clone(), &ctx).await.unwrap();
+            assert_eq!(output.result, example.output, "{}", example.description);
+        }
+    }
+
     #[test]
     fn test_parse_json_input_accepts_valid_json_string() {
         let input = serde_json::json!("{\"ok\":true}");
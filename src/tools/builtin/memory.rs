@@ -18,7 +18,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use crate::context::JobContext;
-use crate::tools::tool::{Tool, ToolError, ToolOutput, require_str};
+use crate::tools::tool::{Tool, ToolError, ToolExample, ToolOutput, require_str};
 use crate::workspace::{Workspace, paths};
 
 // ── WorkspaceResolver ──────────────────────────────────────────────
@@ -72,8 +72,32 @@ fn looks_like_filesystem_path(path: &str) -> bool {
         && (bytes[2] == b'\\' || bytes[2] == b'/')
 }
 
+/// Read an optional non-negative integer parameter that must be at least
+/// `min`. Absent or `null` yields `None`; anything else that isn't such an
+/// integer is rejected rather than silently ignored.
+fn optional_count(
+    params: &serde_json::Value,
+    name: &str,
+    min: u64,
+) -> Result<Option<usize>, ToolError> {
+    match params.get(name) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(value) => value
+            .as_u64()
+            .filter(|n| *n >= min)
+            .map(|n| Some(usize::try_from(n).unwrap_or(usize::MAX)))
+            .ok_or_else(|| {
+                ToolError::InvalidParameters(format!(
+                    "'{}' must be an integer >= {}, got {}",
+                    name, min, value
+                ))
+            }),
+    }
+}
+
 /// Map workspace write errors to tool errors, using `NotAuthorized` for
-/// injection rejections so the LLM gets a clear signal to stop.
+/// injection rejections so the LLM gets a clear signal to stop, and
+/// `InvalidParameters` when a strict append targets a missing file.
 fn map_write_err(e: crate::error::WorkspaceError) -> ToolError {
     match e {
         crate::error::WorkspaceError::InjectionRejected { path, reason } => {
@@ -81,6 +105,11 @@ fn map_write_err(e: crate::error::WorkspaceError) -> ToolError {
                 "content rejected for '{path}': prompt injection detected ({reason})"
             ))
         }
+        crate::error::WorkspaceError::DocumentNotFound { doc_type, .. } => {
+            ToolError::InvalidParameters(format!(
+                "cannot append to '{doc_type}': file does not exist (pass create: true to create it)"
+            ))
+        }
         other => ToolError::ExecutionFailed(format!("Write failed: {other}")),
     }
 }
@@ -114,6 +143,10 @@ impl Tool for MemorySearchTool {
         "memory_search"
     }
 
+    fn category(&self) -> &str {
+        "memory"
+    }
+
     fn description(&self) -> &str {
         "Search past memories, decisions, and context. MUST be called before answering \
          questions about prior work, decisions, dates, people, preferences, or todos. \
@@ -180,6 +213,24 @@ impl Tool for MemorySearchTool {
     fn requires_sanitization(&self) -> bool {
         false // Internal memory, trusted content
     }
+
+    fn examples(&self) -> Vec<ToolExample> {
+        vec![ToolExample::new(
+            "Look up a past decision before answering",
+            serde_json::json!({"query": "which database did we pick for alpha", "limit": 3}),
+            serde_json::json!({
+                "query": "which database did we pick for alpha",
+                "results": [{
+                    "content": "Decided on Postgres for project alpha.",
+                    "score": 0.82,
+                    "path": "projects/alpha/notes.md",
+                    "document_id": "0b6f3c1e-8a44-4d0e-9a37-5c2f0f9e1d2a",
+                    "is_hybrid_match": true
+                }],
+                "result_count": 1
+            }),
+        )]
+    }
 }
 
 /// Tool for writing to workspace memory.
@@ -210,6 +261,10 @@ impl Tool for MemoryWriteTool {
         "memory_write"
     }
 
+    fn category(&self) -> &str {
+        "memory"
+    }
+
     fn description(&self) -> &str {
         "Write to persistent memory (database-backed, NOT the local filesystem). \
          Use for important facts, decisions, preferences, or lessons learned that should \
@@ -246,6 +301,11 @@ impl Tool for MemoryWriteTool {
                     "type": "boolean",
                     "description": "Skip privacy classification and write directly to the specified layer without redirect. Use when you're certain the content belongs in the target layer.",
                     "default": false
+                },
+                "create": {
+                    "type": "boolean",
+                    "description": "When appending to a custom path, set to false to require that the file already exists instead of creating it.",
+                    "default": true
                 }
             },
             "required": ["content"]
@@ -315,6 +375,17 @@ impl Tool for MemoryWriteTool {
             .get("force")
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
+        let create = params
+            .get("create")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        if !create && (!append || layer.is_some() || matches!(target, "memory" | "daily_log")) {
+            return Err(ToolError::InvalidParameters(
+                "create: false is only supported when appending to a custom path without a layer"
+                    .to_string(),
+            ));
+        }
 
         // Parse timezone once for targets that need it (daily_log).
         let tz = crate::timezone::parse_timezone(&ctx.user_timezone).unwrap_or(chrono_tz::Tz::UTC);
@@ -372,7 +443,12 @@ impl Tool for MemoryWriteTool {
                         .map_err(map_write_err)?;
                 }
                 _ => {
-                    if append {
+                    if append && !create {
+                        workspace
+                            .append_existing(&resolved_path, content)
+                            .await
+                            .map_err(map_write_err)?;
+                    } else if append {
                         workspace
                             .append(&resolved_path, content)
                             .await
@@ -457,6 +533,35 @@ impl Tool for MemoryWriteTool {
     fn rate_limit_config(&self) -> Option<crate::tools::tool::ToolRateLimitConfig> {
         Some(crate::tools::tool::ToolRateLimitConfig::new(20, 200))
     }
+
+    fn examples(&self) -> Vec<ToolExample> {
+        vec![
+            ToolExample::new(
+                "Note something in today's log",
+                serde_json::json!({"content": "Shipped the alpha release."}),
+                serde_json::json!({
+                    "status": "written",
+                    "path": "daily/2024-01-15.md",
+                    "append": true,
+                    "content_length": 26
+                }),
+            ),
+            ToolExample::new(
+                "Replace a project notes file",
+                serde_json::json!({
+                    "content": "# Alpha\nUses Postgres.",
+                    "target": "projects/alpha/notes.md",
+                    "append": false
+                }),
+                serde_json::json!({
+                    "status": "written",
+                    "path": "projects/alpha/notes.md",
+                    "append": false,
+                    "content_length": 22
+                }),
+            ),
+        ]
+    }
 }
 
 /// Tool for reading workspace files.
@@ -486,6 +591,10 @@ impl Tool for MemoryReadTool {
         "memory_read"
     }
 
+    fn category(&self) -> &str {
+        "memory"
+    }
+
     fn description(&self) -> &str {
         "Read a file from the workspace memory (database-backed storage). \
          Use this to read files shown by memory_tree. NOT for local filesystem files \
@@ -501,6 +610,16 @@ impl Tool for MemoryReadTool {
                 "path": {
                     "type": "string",
                     "description": "Path to the file (e.g., 'MEMORY.md', 'daily/2024-01-15.md', 'projects/alpha/notes.md')"
+                },
+                "offset": {
+                    "type": "integer",
+                    "description": "Character offset to start reading from. Use with limit to page through large files.",
+                    "minimum": 0
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of characters to return. The result reports has_more when the file continues.",
+                    "minimum": 1
                 }
             },
             "required": ["path"]
@@ -524,7 +643,21 @@ impl Tool for MemoryReadTool {
             )));
         }
 
+        let offset = optional_count(&params, "offset", 0)?;
+        let limit = optional_count(&params, "limit", 1)?;
+
         let workspace = self.resolver.resolve(&ctx.user_id).await;
+
+        if offset.is_some() || limit.is_some() {
+            let slice = workspace
+                .read_range(path, offset.unwrap_or(0), limit.unwrap_or(usize::MAX))
+                .await
+                .map_err(|e| ToolError::ExecutionFailed(format!("Read failed: {}", e)))?;
+            let output = serde_json::to_value(slice)
+                .map_err(|e| ToolError::ExecutionFailed(format!("Serialization failed: {}", e)))?;
+            return Ok(ToolOutput::success(output, start.elapsed()));
+        }
+
         let doc = workspace
             .read(path)
             .await
@@ -543,6 +676,32 @@ impl Tool for MemoryReadTool {
     fn requires_sanitization(&self) -> bool {
         false // Internal memory
     }
+
+    fn examples(&self) -> Vec<ToolExample> {
+        vec![
+            ToolExample::new(
+                "Read long-term memory",
+                serde_json::json!({"path": "MEMORY.md"}),
+                serde_json::json!({
+                    "path": "MEMORY.md",
+                    "content": "- Prefers short answers.",
+                    "word_count": 4,
+                    "updated_at": "2024-01-15T09:30:00+00:00"
+                }),
+            ),
+            ToolExample::new(
+                "Page through a large file",
+                serde_json::json!({"path": "projects/alpha/notes.md", "offset": 0, "limit": 7}),
+                serde_json::json!({
+                    "path": "projects/alpha/notes.md",
+                    "content": "# Alpha",
+                    "offset": 0,
+                    "total_chars": 22,
+                    "has_more": true
+                }),
+            ),
+        ]
+    }
 }
 
 /// Tool for viewing workspace structure as a tree.
@@ -620,6 +779,10 @@ impl Tool for MemoryTreeTool {
         "memory_tree"
     }
 
+    fn category(&self) -> &str {
+        "memory"
+    }
+
     fn description(&self) -> &str {
         "View the workspace memory structure as a tree (database-backed storage). \
          Use memory_read to read files shown here, NOT read_file. \
@@ -674,6 +837,111 @@ impl Tool for MemoryTreeTool {
     fn requires_sanitization(&self) -> bool {
         false // Internal tool
     }
+
+    fn examples(&self) -> Vec<ToolExample> {
+        vec![ToolExample::new(
+            "List the workspace two levels deep",
+            serde_json::json!({"depth": 2}),
+            serde_json::json!([
+                "MEMORY.md",
+                {"daily/": ["2024-01-15.md"]},
+                {"projects/": ["alpha/"]}
+            ]),
+        )]
+    }
+}
+
+/// Tool for reporting workspace storage usage.
+///
+/// Lets the agent notice when it is accumulating large numbers of documents
+/// without walking the tree or reading file contents.
+pub struct WorkspaceStatsTool {
+    resolver: Arc<dyn WorkspaceResolver>,
+}
+
+impl WorkspaceStatsTool {
+    /// Create a new workspace stats tool with a workspace resolver.
+    pub fn new(resolver: Arc<dyn WorkspaceResolver>) -> Self {
+        Self { resolver }
+    }
+
+    /// Create from a fixed workspace (backward compatibility).
+    pub fn from_workspace(workspace: Arc<Workspace>) -> Self {
+        Self {
+            resolver: Arc::new(FixedWorkspaceResolver::new(workspace)),
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for WorkspaceStatsTool {
+    fn name(&self) -> &str {
+        "workspace_stats"
+    }
+
+    fn category(&self) -> &str {
+        "memory"
+    }
+
+    fn description(&self) -> &str {
+        "Report workspace memory usage: total files and bytes, broken down by \
+         top-level directory. Use this to check whether the workspace is growing \
+         too large before writing more documents."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {}
+        })
+    }
+
+    fn output_schema(&self) -> Option<serde_json::Value> {
+        let prefix_usage = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "files": { "type": "integer", "description": "Documents under this prefix" },
+                "bytes": { "type": "integer", "description": "Content size in bytes" }
+            },
+            "required": ["files", "bytes"]
+        });
+        Some(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "total_files": { "type": "integer", "description": "Total number of documents" },
+                "total_bytes": { "type": "integer", "description": "Total content size in bytes" },
+                "by_prefix": {
+                    "type": "object",
+                    "description": "Usage keyed by top-level directory (e.g. \"daily/\"); root files are under \"/\"",
+                    "additionalProperties": prefix_usage
+                }
+            },
+            "required": ["total_files", "total_bytes", "by_prefix"]
+        }))
+    }
+
+    async fn execute(
+        &self,
+        _params: serde_json::Value,
+        ctx: &JobContext,
+    ) -> Result<ToolOutput, ToolError> {
+        let start = std::time::Instant::now();
+
+        let workspace = self.resolver.resolve(&ctx.user_id).await;
+        let usage = workspace
+            .usage()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Usage query failed: {}", e)))?;
+
+        let output = serde_json::to_value(usage)
+            .map_err(|e| ToolError::ExecutionFailed(format!("Serialization failed: {}", e)))?;
+
+        Ok(ToolOutput::success(output, start.elapsed()))
+    }
+
+    fn requires_sanitization(&self) -> bool {
+        false // Internal tool
+    }
 }
 
 // Sanitization tests moved to workspace module (reject_if_injected, is_system_prompt_file).
@@ -697,6 +965,26 @@ mod tests {
         assert!(!looks_like_filesystem_path("projects/alpha/notes.md"));
     }
 
+    #[test]
+    fn optional_count_rejects_invalid_values() {
+        let params = serde_json::json!({"offset": 4, "limit": null});
+        assert_eq!(optional_count(&params, "offset", 0).unwrap(), Some(4));
+        assert_eq!(optional_count(&params, "limit", 1).unwrap(), None);
+        assert_eq!(optional_count(&params, "missing", 1).unwrap(), None);
+
+        for bad in [
+            serde_json::json!({"limit": 0}),
+            serde_json::json!({"limit": -3}),
+            serde_json::json!({"limit": 2.5}),
+            serde_json::json!({"limit": "10"}),
+        ] {
+            assert!(matches!(
+                optional_count(&bad, "limit", 1),
+                Err(ToolError::InvalidParameters(_))
+            ));
+        }
+    }
+
     #[cfg(feature = "postgres")]
     mod postgres_schema_tests {
         use super::*;
@@ -931,6 +1219,84 @@ mod tests {
             Arc::new(backend)
         }
 
+        #[tokio::test]
+        async fn test_workspace_stats_reports_counts_and_bytes() {
+            let db = make_test_db().await;
+            let ws = Arc::new(Workspace::new_with_db("stats_user", db));
+            ws.write("notes/a.md", "first").await.expect("write a");
+            ws.write("notes/b.md", "second").await.expect("write b");
+
+            let tool = WorkspaceStatsTool::from_workspace(ws);
+            let ctx = JobContext::with_user("stats_user", "test", "test");
+            let output = tool
+                .execute(serde_json::json!({}), &ctx)
+                .await
+                .expect("execute");
+
+            assert_eq!(output.result["total_files"], 2);
+            assert_eq!(output.result["by_prefix"]["notes/"]["files"], 2);
+            assert!(output.result["total_bytes"].as_u64().unwrap() > 0);
+
+            // Every field the output schema declares must actually be present.
+            let schema = tool.output_schema().expect("output schema");
+            for field in schema["required"].as_array().unwrap() {
+                let field = field.as_str().unwrap();
+                assert!(output.result.get(field).is_some(), "missing {field}");
+            }
+        }
+
+        #[tokio::test]
+        async fn test_memory_write_strict_append_rejects_missing_file() {
+            let db = make_test_db().await;
+            let ws = Arc::new(Workspace::new_with_db("append_user", db));
+            ws.write("notes/exp.md", "# Experiment")
+                .await
+                .expect("write");
+
+            let tool = MemoryWriteTool::from_workspace(Arc::clone(&ws));
+            let ctx = JobContext::with_user("append_user", "test", "test");
+            tool.execute(
+                serde_json::json!({"content": "- observation 1", "target": "notes/exp.md", "create": false}),
+                &ctx,
+            )
+            .await
+            .expect("append to existing");
+
+            ws.delete("notes/exp.md").await.expect("delete");
+            let err = tool
+                .execute(
+                    serde_json::json!({"content": "- observation 2", "target": "notes/exp.md", "create": false}),
+                    &ctx,
+                )
+                .await
+                .expect_err("missing file");
+            assert!(matches!(err, ToolError::InvalidParameters(_)), "{err:?}");
+            assert!(ws.read("notes/exp.md").await.is_err());
+        }
+
+        #[tokio::test]
+        async fn test_memory_read_pages_with_offset_and_limit() {
+            let db = make_test_db().await;
+            let ws = Arc::new(Workspace::new_with_db("paging_user", db));
+            ws.write("notes/long.md", "abcdefghij")
+                .await
+                .expect("write");
+
+            let tool = MemoryReadTool::from_workspace(ws);
+            let ctx = JobContext::with_user("paging_user", "test", "test");
+            let output = tool
+                .execute(
+                    serde_json::json!({"path": "notes/long.md", "offset": 4, "limit": 3}),
+                    &ctx,
+                )
+                .await
+                .expect("execute");
+
+            assert_eq!(output.result["content"], "efg");
+            assert_eq!(output.result["total_chars"], 10);
+            assert_eq!(output.result["has_more"], true);
+        }
+
         #[tokio::test]
         async fn test_workspace_pool_resolver_returns_different_workspaces() {
             let db = make_test_db().await;
@@ -1,5 +1,7 @@
 //! Built-in tools that come with the agent.
 
+pub mod bibliography;
+mod data_parse;
 mod echo;
 pub mod extension_tools;
 mod file;
@@ -17,6 +19,8 @@ pub mod skill_tools;
 mod time;
 mod tool_info;
 
+pub use bibliography::BibliographyTool;
+pub use data_parse::DataParseTool;
 pub use echo::EchoTool;
 pub use extension_tools::{
     ExtensionInfoTool, ToolActivateTool, ToolAuthTool, ToolInstallTool, ToolListTool,
@@ -29,7 +33,9 @@ pub use job::{
     PromptQueue, SchedulerSlot,
 };
 pub use json::JsonTool;
-pub use memory::{MemoryReadTool, MemorySearchTool, MemoryTreeTool, MemoryWriteTool};
+pub use memory::{
+    MemoryReadTool, MemorySearchTool, MemoryTreeTool, MemoryWriteTool, WorkspaceStatsTool,
+};
 pub use message::MessageTool;
 pub use restart::RestartTool;
 pub use routine::{
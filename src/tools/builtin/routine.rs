@@ -1114,6 +1114,10 @@ impl Tool for RoutineCreateTool {
         "routine_create"
     }
 
+    fn category(&self) -> &str {
+        "routines"
+    }
+
     fn description(&self) -> &str {
         "Create a new routine (scheduled or event-driven task). \
          Supports cron schedules, event pattern matching, system events, and manual triggers. \
@@ -1244,6 +1248,10 @@ impl Tool for RoutineListTool {
         "routine_list"
     }
 
+    fn category(&self) -> &str {
+        "routines"
+    }
+
     fn description(&self) -> &str {
         "List all routines with their status, trigger info, and next fire time."
     }
@@ -1335,6 +1343,10 @@ impl Tool for RoutineUpdateTool {
         "routine_update"
     }
 
+    fn category(&self) -> &str {
+        "routines"
+    }
+
     fn description(&self) -> &str {
         "Update an existing routine. Can change prompt, description, enabled state, cron schedule/timezone, \
          Pass the routine name and only the fields you want to change. This does not convert trigger types. \
@@ -1498,6 +1510,10 @@ impl Tool for RoutineDeleteTool {
         "routine_delete"
     }
 
+    fn category(&self) -> &str {
+        "routines"
+    }
+
     fn description(&self) -> &str {
         "Delete a routine permanently. This also removes all run history."
     }
@@ -1585,6 +1601,10 @@ impl Tool for RoutineFireTool {
         "routine_fire"
     }
 
+    fn category(&self) -> &str {
+        "routines"
+    }
+
     fn description(&self) -> &str {
         "Manually trigger a routine to run immediately, bypassing schedule, trigger type, and cooldown."
     }
@@ -1665,6 +1685,10 @@ impl Tool for RoutineHistoryTool {
         "routine_history"
     }
 
+    fn category(&self) -> &str {
+        "routines"
+    }
+
     fn description(&self) -> &str {
         "View the execution history of a routine. Shows recent runs with status, duration, and results."
     }
@@ -1804,6 +1828,10 @@ impl Tool for EventEmitTool {
         "event_emit"
     }
 
+    fn category(&self) -> &str {
+        "routines"
+    }
+
     fn description(&self) -> &str {
         "Emit a structured system event to routines with a system_event trigger. \
          Use this to trigger routines from tool workflows without waiting for cron."
@@ -37,6 +37,10 @@ impl Tool for SecretListTool {
         "secret_list"
     }
 
+    fn category(&self) -> &str {
+        "secrets"
+    }
+
     fn description(&self) -> &str {
         "List all stored secrets by name. Never returns values — only names and \
          optional provider metadata. Use this to check what credentials are available \
@@ -101,6 +105,10 @@ impl Tool for SecretDeleteTool {
         "secret_delete"
     }
 
+    fn category(&self) -> &str {
+        "secrets"
+    }
+
     fn description(&self) -> &str {
         "Permanently delete a stored secret by name. This cannot be undone."
     }
@@ -56,7 +56,8 @@ use tokio::process::Command;
 use crate::context::JobContext;
 use crate::sandbox::{SandboxManager, SandboxPolicy};
 use crate::tools::tool::{
-    ApprovalRequirement, RiskLevel, Tool, ToolDomain, ToolError, ToolOutput, require_str,
+    ApprovalRequirement, RiskLevel, Tool, ToolDomain, ToolError, ToolExample, ToolOutput,
+    require_str,
 };
 
 /// Maximum output size before truncation (64KB).
@@ -835,6 +836,10 @@ impl Tool for ShellTool {
         "shell"
     }
 
+    fn category(&self) -> &str {
+        "development"
+    }
+
     fn description(&self) -> &str {
         "Execute shell commands. Use for running builds, tests, git operations, and other CLI tasks. \
          Commands run in a subprocess with captured output. Long-running commands have a timeout. \
@@ -921,6 +926,31 @@ impl Tool for ShellTool {
     fn rate_limit_config(&self) -> Option<crate::tools::tool::ToolRateLimitConfig> {
         Some(crate::tools::tool::ToolRateLimitConfig::new(30, 300))
     }
+
+    fn examples(&self) -> Vec<ToolExample> {
+        vec![
+            ToolExample::new(
+                "Check the working tree",
+                serde_json::json!({"command": "git status --short"}),
+                serde_json::json!({
+                    "output": " M src/main.rs\n",
+                    "exit_code": 0,
+                    "success": true,
+                    "sandboxed": false
+                }),
+            ),
+            ToolExample::new(
+                "Run the test suite in a subdirectory with a longer timeout",
+                serde_json::json!({"command": "cargo test", "workdir": "crates/core", "timeout": 600}),
+                serde_json::json!({
+                    "output": "test result: FAILED. 41 passed; 1 failed\n",
+                    "exit_code": 101,
+                    "success": false,
+                    "sandboxed": false
+                }),
+            ),
+        ]
+    }
 }
 
 /// Truncate output to fit within limits (UTF-8 safe).
@@ -30,6 +30,10 @@ impl Tool for SkillListTool {
         "skill_list"
     }
 
+    fn category(&self) -> &str {
+        "skills"
+    }
+
     fn description(&self) -> &str {
         "List all loaded skills with their trust level, source, and activation keywords."
     }
@@ -129,6 +133,10 @@ impl Tool for SkillSearchTool {
         "skill_search"
     }
 
+    fn category(&self) -> &str {
+        "skills"
+    }
+
     fn description(&self) -> &str {
         "Search for skills in the ClawHub catalog and among locally loaded skills."
     }
@@ -241,6 +249,10 @@ impl Tool for SkillSearchTool {
 
         Ok(ToolOutput::success(output, start.elapsed()))
     }
+
+    fn requires_network(&self) -> bool {
+        true
+    }
 }
 
 // ── skill_install ───────────────────────────────────────────────────────
@@ -265,6 +277,10 @@ impl Tool for SkillInstallTool {
         "skill_install"
     }
 
+    fn category(&self) -> &str {
+        "skills"
+    }
+
     fn description(&self) -> &str {
         "Install a skill from SKILL.md content, a URL, or by name from the ClawHub catalog."
     }
@@ -376,6 +392,10 @@ impl Tool for SkillInstallTool {
     fn requires_approval(&self, _params: &serde_json::Value) -> ApprovalRequirement {
         ApprovalRequirement::UnlessAutoApproved
     }
+
+    fn requires_network(&self) -> bool {
+        true
+    }
 }
 
 /// Validate that a URL is safe to fetch (SSRF prevention).
@@ -712,6 +732,10 @@ impl Tool for SkillRemoveTool {
         "skill_remove"
     }
 
+    fn category(&self) -> &str {
+        "skills"
+    }
+
     fn description(&self) -> &str {
         "Permanently remove an installed skill from disk. This action cannot be undone — \
          the skill files will be deleted."
@@ -1,9 +1,10 @@
 //! On-demand tool discovery (like CLI `--help`).
 //!
 //! Three levels of detail:
-//! - Default: name, description, parameter names (compact ~150 bytes)
+//! - Default: name, description, version, parameter names (compact ~150 bytes)
 //! - `detail: "summary"`: adds curated rules, notes, and examples
-//! - `detail: "schema"` / `include_schema: true`: adds the full typed JSON Schema
+//! - `detail: "schema"` / `include_schema: true`: adds the full typed JSON Schema,
+//!   plus the output schema when the tool declares one
 //!
 //! Keeps the tools array compact (WASM tools use permissive schemas)
 //! while allowing precise discovery when needed.
@@ -149,15 +150,23 @@ impl Tool for ToolInfoTool {
         let mut info = serde_json::json!({
             "name": tool.name(),
             "description": tool.description(),
+            "version": tool.version(),
             "parameters": param_names,
         });
 
         match detail {
             ToolInfoDetail::Names => {}
             ToolInfoDetail::Summary => {
-                let summary = tool
+                let mut summary = tool
                     .discovery_summary()
                     .unwrap_or_else(|| fallback_summary(&schema));
+                if summary.examples.is_empty() {
+                    summary.examples = tool
+                        .examples()
+                        .into_iter()
+                        .filter_map(|example| serde_json::to_value(example).ok())
+                        .collect();
+                }
                 info["summary"] = serde_json::to_value(summary).map_err(|err| {
                     ToolError::ExecutionFailed(format!(
                         "failed to serialize discovery summary: {err}"
@@ -166,6 +175,9 @@ impl Tool for ToolInfoTool {
             }
             ToolInfoDetail::Schema => {
                 info["schema"] = schema;
+                if let Some(output_schema) = tool.output_schema() {
+                    info["output_schema"] = output_schema;
+                }
             }
         }
 
@@ -194,6 +206,7 @@ mod tests {
         let info = &result.result;
         assert_eq!(info["name"], "echo");
         assert!(!info["description"].as_str().unwrap().is_empty());
+        assert_eq!(info["version"], "1.0.0");
         // Default: parameters is an array of names, not the full schema
         assert!(info["parameters"].is_array());
         assert!(
@@ -233,6 +246,28 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_tool_info_summary_includes_tool_examples() {
+        use crate::tools::builtin::JsonTool;
+
+        let registry = Arc::new(ToolRegistry::new());
+        registry.register(Arc::new(JsonTool)).await;
+
+        let tool = ToolInfoTool::new(Arc::downgrade(&registry));
+        let ctx = JobContext::default();
+        let result = tool
+            .execute(
+                serde_json::json!({"name": "json", "detail": "summary"}),
+                &ctx,
+            )
+            .await
+            .unwrap();
+
+        let examples = result.result["summary"]["examples"].as_array().unwrap();
+        assert!(!examples.is_empty());
+        assert!(examples[0]["params"]["operation"].is_string());
+    }
+
     #[tokio::test]
     async fn test_tool_info_with_schema() {
         let registry = Arc::new(ToolRegistry::new());
@@ -253,6 +288,8 @@ mod tests {
         // With include_schema: true, schema field should be present
         assert!(info["schema"].is_object());
         assert!(info["schema"]["properties"].is_object());
+        // Echo declares no output schema, so none is reported.
+        assert!(info.get("output_schema").is_none());
     }
 
     #[tokio::test]
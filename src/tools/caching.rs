@@ -0,0 +1,372 @@
+//! Tool wrapper that memoizes results of pure tools.
+//!
+//! Agents often retry an identical call. For tools that declare
+//! [`Tool::is_cacheable`], [`CachingTool`] keeps successful results in a
+//! bounded LRU keyed by `SHA-256(name + "\0" + version + "\0" + params)`,
+//! where params are serialized with object keys sorted so that key order
+//! does not affect the hit rate. Entries expire after a TTL; errors are
+//! never cached. Tools that aren't cacheable pass straight through.
+
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use lru::LruCache;
+use rust_decimal::Decimal;
+use sha2::{Digest, Sha256};
+
+use crate::context::JobContext;
+use crate::tools::tool::{
+    ApprovalRequirement, RiskLevel, Tool, ToolDiscoverySummary, ToolDomain, ToolError, ToolExample,
+    ToolOutput, ToolRateLimitConfig, ToolSchema,
+};
+
+/// Default number of cached results per wrapped tool.
+pub const DEFAULT_MAX_ENTRIES: usize = 256;
+
+/// Default lifetime of a cached result.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(300);
+
+/// Wraps a tool and memoizes its successful results when it is cacheable.
+///
+/// The cache lock is never held across `.await`, so a synchronous mutex is
+/// used (same reasoning as the embedding cache).
+pub struct CachingTool<T: Tool> {
+    inner: T,
+    ttl: Duration,
+    cache: Mutex<LruCache<[u8; 32], (ToolOutput, Instant)>>,
+}
+
+impl<T: Tool> CachingTool<T> {
+    /// Wrap `inner` with the default size bound and TTL.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            ttl: DEFAULT_TTL,
+            cache: Mutex::new(LruCache::new(
+                NonZeroUsize::new(DEFAULT_MAX_ENTRIES).expect("non-zero constant"), // safety: constant > 0
+            )),
+        }
+    }
+
+    /// Override the maximum number of cached results (clamped to at least 1).
+    pub fn with_max_entries(self, max_entries: usize) -> Self {
+        let cap = NonZeroUsize::new(max_entries.max(1)).expect("clamped to >= 1"); // safety: always >= 1
+        Self {
+            cache: Mutex::new(LruCache::new(cap)),
+            ..self
+        }
+    }
+
+    /// Override how long a cached result stays valid.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Access the wrapped tool.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Number of entries currently in the cache (including expired ones not
+    /// yet evicted).
+    pub fn len(&self) -> usize {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop all cached results.
+    pub fn clear(&self) {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    fn cache_key(&self, params: &serde_json::Value) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(self.inner.name().as_bytes());
+        hasher.update(b"\0");
+        hasher.update(self.inner.version().as_bytes());
+        hasher.update(b"\0");
+        hash_canonical(&mut hasher, params);
+        hasher.finalize().into()
+    }
+}
+
+/// Feed `value` into `hasher` with object keys in sorted order, so that
+/// `{"a":1,"b":2}` and `{"b":2,"a":1}` hash identically regardless of
+/// whether serde_json preserves insertion order.
+fn hash_canonical(hasher: &mut Sha256, value: &serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            hasher.update(b"{");
+            for key in keys {
+                hasher.update(serde_json::Value::from(key.as_str()).to_string().as_bytes());
+                hasher.update(b":");
+                hash_canonical(hasher, &map[key]);
+                hasher.update(b",");
+            }
+            hasher.update(b"}");
+        }
+        serde_json::Value::Array(items) => {
+            hasher.update(b"[");
+            for item in items {
+                hash_canonical(hasher, item);
+                hasher.update(b",");
+            }
+            hasher.update(b"]");
+        }
+        scalar => hasher.update(scalar.to_string().as_bytes()),
+    }
+}
+
+#[async_trait]
+impl<T: Tool> Tool for CachingTool<T> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        self.inner.parameters_schema()
+    }
+
+    async fn execute(
+        &self,
+        params: serde_json::Value,
+        ctx: &JobContext,
+    ) -> Result<ToolOutput, ToolError> {
+        if !self.inner.is_cacheable() {
+            return self.inner.execute(params, ctx).await;
+        }
+
+        let key = self.cache_key(&params);
+        {
+            let mut guard = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+            match guard.get(&key) {
+                Some((output, stored_at)) if stored_at.elapsed() < self.ttl => {
+                    tracing::trace!(tool = self.inner.name(), "tool result cache hit");
+                    return Ok(output.clone());
+                }
+                Some(_) => {
+                    guard.pop(&key);
+                }
+                None => {}
+            }
+        }
+
+        let output = self.inner.execute(params, ctx).await?;
+        self.cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .put(key, (output.clone(), Instant::now()));
+        Ok(output)
+    }
+
+    fn estimated_cost(&self, params: &serde_json::Value) -> Option<Decimal> {
+        self.inner.estimated_cost(params)
+    }
+
+    fn estimated_duration(&self, params: &serde_json::Value) -> Option<Duration> {
+        self.inner.estimated_duration(params)
+    }
+
+    fn requires_sanitization(&self) -> bool {
+        self.inner.requires_sanitization()
+    }
+
+    fn requires_network(&self) -> bool {
+        self.inner.requires_network()
+    }
+
+    fn is_cacheable(&self) -> bool {
+        self.inner.is_cacheable()
+    }
+
+    fn risk_level_for(&self, params: &serde_json::Value) -> RiskLevel {
+        self.inner.risk_level_for(params)
+    }
+
+    fn requires_approval(&self, params: &serde_json::Value) -> ApprovalRequirement {
+        self.inner.requires_approval(params)
+    }
+
+    fn execution_timeout(&self) -> Duration {
+        self.inner.execution_timeout()
+    }
+
+    fn domain(&self) -> ToolDomain {
+        self.inner.domain()
+    }
+
+    fn category(&self) -> &str {
+        self.inner.category()
+    }
+
+    fn sensitive_params(&self) -> &[&str] {
+        self.inner.sensitive_params()
+    }
+
+    fn rate_limit_config(&self) -> Option<ToolRateLimitConfig> {
+        self.inner.rate_limit_config()
+    }
+
+    fn webhook_capability(&self) -> Option<crate::tools::wasm::WebhookCapability> {
+        self.inner.webhook_capability()
+    }
+
+    fn discovery_schema(&self) -> serde_json::Value {
+        self.inner.discovery_schema()
+    }
+
+    fn discovery_summary(&self) -> Option<ToolDiscoverySummary> {
+        self.inner.discovery_summary()
+    }
+
+    fn version(&self) -> &str {
+        self.inner.version()
+    }
+
+    fn output_schema(&self) -> Option<serde_json::Value> {
+        self.inner.output_schema()
+    }
+
+    fn examples(&self) -> Vec<ToolExample> {
+        self.inner.examples()
+    }
+
+    fn schema(&self) -> ToolSchema {
+        self.inner.schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    struct CountingTool {
+        calls: Arc<AtomicUsize>,
+        cacheable: bool,
+    }
+
+    #[async_trait]
+    impl Tool for CountingTool {
+        fn name(&self) -> &str {
+            "counting"
+        }
+        fn description(&self) -> &str {
+            "Doubles a number and counts how often it ran"
+        }
+        fn parameters_schema(&self) -> serde_json::Value {
+            serde_json::json!({"type": "object", "properties": {"x": {"type": "number"}}})
+        }
+        async fn execute(
+            &self,
+            params: serde_json::Value,
+            _ctx: &JobContext,
+        ) -> Result<ToolOutput, ToolError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let x = params["x"]
+                .as_f64()
+                .ok_or_else(|| ToolError::InvalidParameters("missing 'x'".to_string()))?;
+            Ok(ToolOutput::success(
+                serde_json::json!({"doubled": x * 2.0}),
+                Duration::ZERO,
+            ))
+        }
+        fn is_cacheable(&self) -> bool {
+            self.cacheable
+        }
+    }
+
+    fn counting(cacheable: bool) -> (CachingTool<CountingTool>, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let tool = CachingTool::new(CountingTool {
+            calls: Arc::clone(&calls),
+            cacheable,
+        });
+        (tool, calls)
+    }
+
+    #[tokio::test]
+    async fn test_identical_calls_execute_once() {
+        let (tool, calls) = counting(true);
+        let ctx = JobContext::default();
+
+        let first = tool
+            .execute(serde_json::json!({"x": 21, "unit": "mg"}), &ctx)
+            .await
+            .unwrap();
+        // Same params in a different key order still hit.
+        let second = tool
+            .execute(serde_json::json!({"unit": "mg", "x": 21}), &ctx)
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first.result, second.result);
+        assert_eq!(second.result["doubled"], 42.0);
+
+        tool.execute(serde_json::json!({"x": 1}), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_errors_and_uncacheable_tools_are_not_cached() {
+        let ctx = JobContext::default();
+
+        let (tool, calls) = counting(true);
+        assert!(tool.execute(serde_json::json!({}), &ctx).await.is_err());
+        assert!(tool.execute(serde_json::json!({}), &ctx).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert!(tool.is_empty());
+
+        let (tool, calls) = counting(false);
+        tool.execute(serde_json::json!({"x": 2}), &ctx)
+            .await
+            .unwrap();
+        tool.execute(serde_json::json!({"x": 2}), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_expired_and_evicted_entries_rerun() {
+        let ctx = JobContext::default();
+
+        let (tool, calls) = counting(true);
+        let tool = tool.with_ttl(Duration::ZERO);
+        tool.execute(serde_json::json!({"x": 3}), &ctx)
+            .await
+            .unwrap();
+        tool.execute(serde_json::json!({"x": 3}), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        let (tool, calls) = counting(true);
+        let tool = tool.with_max_entries(1);
+        for x in [1, 2, 1] {
+            tool.execute(serde_json::json!({"x": x}), &ctx)
+                .await
+                .unwrap();
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(tool.len(), 1);
+    }
+}
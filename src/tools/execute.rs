@@ -12,6 +12,24 @@ use crate::llm::ChatMessage;
 use crate::safety::SafetyLayer;
 use crate::tools::{ToolRegistry, prepare_tool_params, redact_params};
 
+/// Refuse network-capable tools when the deployment or the job runs in
+/// offline mode.
+///
+/// Returns the error to surface, or `None` if the tool may run.
+pub(crate) fn offline_violation(
+    tools: &ToolRegistry,
+    tool: &dyn crate::tools::Tool,
+    job_ctx: &JobContext,
+) -> Option<crate::error::ToolError> {
+    if (tools.is_offline() || job_ctx.offline) && tool.requires_network() {
+        return Some(crate::error::ToolError::Disabled {
+            name: tool.name().to_string(),
+            reason: "network disabled in offline mode".to_string(),
+        });
+    }
+    None
+}
+
 /// Execute a tool with safety checks: lookup → validate → timeout → execute → serialize.
 ///
 /// This is the single canonical implementation of tool execution. All consumers
@@ -37,6 +55,10 @@ pub async fn execute_tool_with_safety(
             name: tool_name.to_string(),
         })?;
 
+    if let Some(err) = offline_violation(tools, tool.as_ref(), job_ctx) {
+        return Err(err.into());
+    }
+
     let normalized_params = prepare_tool_params(tool.as_ref(), &params);
 
     // Validate tool parameters
@@ -63,10 +85,13 @@ pub async fn execute_tool_with_safety(
     );
 
     // Execute with per-tool timeout
+    let permit = tools.acquire_execution_permit(tool.as_ref()).await;
     let timeout = tool.execution_timeout();
     let start = std::time::Instant::now();
     let result = tokio::time::timeout(timeout, tool.execute(normalized_params, job_ctx)).await;
     let elapsed = start.elapsed();
+    drop(permit);
+    let result = result.map(|r| r.map(|output| output.with_attribution(tool_name, elapsed)));
 
     match &result {
         Ok(Ok(output)) => {
@@ -103,9 +128,19 @@ pub async fn execute_tool_with_safety(
             name: tool_name.to_string(),
             timeout,
         })?
-        .map_err(|e| crate::error::ToolError::ExecutionFailed {
-            name: tool_name.to_string(),
-            reason: e.to_string(),
+        .map_err(|e| match e {
+            crate::tools::ToolError::RateLimited {
+                retry_after,
+                message,
+            } => crate::error::ToolError::RateLimited {
+                name: tool_name.to_string(),
+                retry_after,
+                message,
+            },
+            other => crate::error::ToolError::ExecutionFailed {
+                name: tool_name.to_string(),
+                reason: other.to_string(),
+            },
         })?;
 
     serde_json::to_string_pretty(&result.result).map_err(|e| {
@@ -276,6 +311,31 @@ mod tests {
         }
     }
 
+    struct NetworkTool;
+
+    #[async_trait::async_trait]
+    impl Tool for NetworkTool {
+        fn name(&self) -> &str {
+            "network_tool"
+        }
+        fn description(&self) -> &str {
+            "Pretends to make a network request"
+        }
+        fn parameters_schema(&self) -> serde_json::Value {
+            serde_json::json!({"type": "object", "properties": {}})
+        }
+        async fn execute(
+            &self,
+            _: serde_json::Value,
+            _: &JobContext,
+        ) -> Result<ToolOutput, ToolError> {
+            panic!("network tool must not run in offline mode");
+        }
+        fn requires_network(&self) -> bool {
+            true
+        }
+    }
+
     fn test_safety() -> SafetyLayer {
         SafetyLayer::new(&crate::config::SafetyConfig {
             max_output_length: 100_000,
@@ -295,6 +355,77 @@ mod tests {
         registry
     }
 
+    #[tokio::test]
+    async fn test_offline_mode_blocks_network_tools_only() {
+        let registry = registry_with(vec![Arc::new(NetworkTool), Arc::new(EchoTool)]).await;
+        let safety = test_safety();
+        let ctx = test_job_ctx().with_offline(true);
+
+        let result = execute_tool_with_safety(
+            &registry,
+            &safety,
+            "network_tool",
+            serde_json::json!({}),
+            &ctx,
+        )
+        .await;
+        assert!(
+            matches!(
+                result,
+                Err(crate::error::Error::Tool(
+                    crate::error::ToolError::Disabled { .. }
+                ))
+            ),
+            "network tool should be disabled offline, got: {result:?}"
+        );
+
+        let result = execute_tool_with_safety(
+            &registry,
+            &safety,
+            "echo",
+            serde_json::json!({"message": "hi"}),
+            &ctx,
+        )
+        .await;
+        assert!(result.is_ok(), "offline mode should not block echo");
+    }
+
+    #[tokio::test]
+    async fn test_offline_registry_refuses_skill_install() {
+        let skills_dir = tempfile::tempdir().unwrap();
+        let skills = Arc::new(std::sync::RwLock::new(crate::skills::SkillRegistry::new(
+            skills_dir.path().to_path_buf(),
+        )));
+        let catalog = Arc::new(crate::skills::catalog::SkillCatalog::with_url(
+            "http://127.0.0.1:1",
+        ));
+        let registry = ToolRegistry::new().with_offline(true);
+        registry
+            .register(Arc::new(crate::tools::builtin::SkillInstallTool::new(
+                skills, catalog,
+            )))
+            .await;
+
+        // The job itself is not offline; the deployment-wide flag applies.
+        let result = execute_tool_with_safety(
+            &registry,
+            &test_safety(),
+            "skill_install",
+            serde_json::json!({"name": "some-skill"}),
+            &test_job_ctx(),
+        )
+        .await;
+        assert!(
+            matches!(
+                result,
+                Err(crate::error::Error::Tool(
+                    crate::error::ToolError::Disabled { .. }
+                ))
+            ),
+            "skill_install should be disabled offline, got: {result:?}"
+        );
+    }
+
     #[tokio::test]
     async fn test_execute_empty_tool_name_returns_not_found() {
         // Regression: execute_tool_with_safety must reject empty tool names
@@ -668,6 +668,10 @@ impl Tool for McpToolWrapper {
             ApprovalRequirement::Never
         }
     }
+
+    fn requires_network(&self) -> bool {
+        true
+    }
 }
 
 /// Remove top-level keys whose value is JSON null from an object.
@@ -1295,6 +1299,17 @@ mod tests {
         assert!(schema["properties"]["input"].is_object());
     }
 
+    #[test]
+    fn test_mcp_tool_wrapper_requires_network() {
+        let client = Arc::new(McpClient::new("http://localhost:8080"));
+        let wrapper = McpToolWrapper {
+            tool: make_test_mcp_tool(false),
+            prefixed_name: "mcp__s__do_thing".to_string(),
+            client,
+        };
+        assert!(wrapper.requires_network());
+    }
+
     #[test]
     fn test_mcp_tool_wrapper_requires_sanitization() {
         let client = Arc::new(McpClient::new("http://localhost:8080"));
@@ -10,11 +10,13 @@
 mod autonomy;
 pub mod builder;
 pub mod builtin;
+pub mod caching;
 mod coercion;
 pub mod execute;
 pub mod mcp;
 pub mod rate_limiter;
 pub mod redaction;
+pub mod sanitizing;
 pub mod schema_validator;
 pub mod wasm;
 
@@ -30,9 +32,11 @@ pub use builder::{
     LlmSoftwareBuilder, SoftwareBuilder, SoftwareType, Template, TemplateEngine, TemplateType,
     TestCase, TestHarness, TestResult, TestSuite, ValidationError, ValidationResult, WasmValidator,
 };
+pub use caching::CachingTool;
 pub(crate) use coercion::prepare_tool_params;
 pub use rate_limiter::RateLimiter;
-pub use registry::ToolRegistry;
+pub use registry::{ExecutionPermit, ToolRegistry};
+pub use sanitizing::SanitizingTool;
 pub use tool::{
     ApprovalContext, ApprovalRequirement, RiskLevel, Tool, ToolDomain, ToolError, ToolOutput,
     ToolRateLimitConfig, redact_params, validate_tool_schema,
@@ -1,9 +1,10 @@
 //! Tool registry for managing available tools.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
+use std::time::Duration;
 
-use tokio::sync::RwLock;
+use tokio::sync::{OwnedSemaphorePermit, RwLock, Semaphore};
 
 use crate::context::ContextManager;
 use crate::db::Database;
@@ -17,15 +18,17 @@ use crate::tools::builder::{
     BuildSoftwareTool, BuilderConfig, LlmSoftwareBuilder, SoftwareBuilder,
 };
 use crate::tools::builtin::{
-    ApplyPatchTool, CancelJobTool, CreateJobTool, EchoTool, ExtensionInfoTool, HttpTool,
-    JobEventsTool, JobPromptTool, JobStatusTool, JsonTool, ListDirTool, ListJobsTool,
+    ApplyPatchTool, CancelJobTool, CreateJobTool, DataParseTool, EchoTool, ExtensionInfoTool,
+    HttpTool, JobEventsTool, JobPromptTool, JobStatusTool, JsonTool, ListDirTool, ListJobsTool,
     MemoryReadTool, MemorySearchTool, MemoryTreeTool, MemoryWriteTool, PromptQueue, ReadFileTool,
     ShellTool, SkillInstallTool, SkillListTool, SkillRemoveTool, SkillSearchTool, TimeTool,
     ToolActivateTool, ToolAuthTool, ToolInstallTool, ToolListTool, ToolRemoveTool, ToolSearchTool,
-    ToolUpgradeTool, WriteFileTool,
+    ToolUpgradeTool, WorkspaceStatsTool, WriteFileTool,
 };
+use crate::tools::caching::{self, CachingTool};
 use crate::tools::rate_limiter::RateLimiter;
-use crate::tools::tool::{ApprovalRequirement, Tool, ToolDomain};
+use crate::tools::sanitizing::SanitizingTool;
+use crate::tools::tool::{ApprovalRequirement, Tool, ToolDomain, ToolError, ToolOutput};
 use crate::tools::wasm::{
     Capabilities, OAuthRefreshConfig, ResourceLimits, SharedCredentialRegistry, WasmError,
     WasmStorageError, WasmToolRuntime, WasmToolStore, WasmToolWrapper,
@@ -39,6 +42,7 @@ const PROTECTED_TOOL_NAMES: &[&str] = &[
     "echo",
     "time",
     "json",
+    "data_parse",
     "http",
     "shell",
     "read_file",
@@ -49,6 +53,8 @@ const PROTECTED_TOOL_NAMES: &[&str] = &[
     "memory_write",
     "memory_read",
     "memory_tree",
+    "workspace_stats",
+    "bibliography",
     "create_job",
     "list_jobs",
     "job_status",
@@ -93,6 +99,23 @@ pub struct ToolRegistry {
     rate_limiter: RateLimiter,
     /// Reference to the message tool for setting context per-turn.
     message_tool: RwLock<Option<Arc<crate::tools::builtin::MessageTool>>>,
+    /// Cap on concurrently executing tools. `None` means unlimited.
+    execution_limit: Option<Arc<Semaphore>>,
+    /// Tighter cap shared by network tools, applied on top of `execution_limit`.
+    network_limit: Option<Arc<Semaphore>>,
+    /// Deployment-wide offline mode: refuse every tool that
+    /// `requires_network()`, regardless of the job's own flag.
+    offline: bool,
+    /// Result cache size and TTL applied to cacheable built-in tools.
+    /// `None` disables caching.
+    result_cache: Option<(usize, Duration)>,
+}
+
+/// Permits held while a tool executes; released on drop.
+#[must_use = "permits are released as soon as they are dropped"]
+pub struct ExecutionPermit {
+    _network: Option<OwnedSemaphorePermit>,
+    _global: Option<OwnedSemaphorePermit>,
 }
 
 impl ToolRegistry {
@@ -114,6 +137,65 @@ impl ToolRegistry {
             secrets_store: None,
             rate_limiter: RateLimiter::new(),
             message_tool: RwLock::new(None),
+            execution_limit: None,
+            network_limit: None,
+            offline: false,
+            result_cache: Some((caching::DEFAULT_MAX_ENTRIES, caching::DEFAULT_TTL)),
+        }
+    }
+
+    /// Refuse network-capable tools for every job run through this registry.
+    ///
+    /// Set from `AGENT_OFFLINE` for air-gapped deployments. Jobs can also opt
+    /// in individually via [`JobContext::with_offline`](crate::context::JobContext::with_offline).
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Whether this registry runs in offline mode.
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Configure the result cache for cacheable built-in tools (see
+    /// [`Tool::is_cacheable`]). `max_entries == 0` disables caching.
+    pub fn with_result_cache(mut self, max_entries: usize, ttl: Duration) -> Self {
+        self.result_cache = (max_entries > 0).then_some((max_entries, ttl));
+        self
+    }
+
+    /// Limit how many tools may execute at once across all callers.
+    pub fn with_concurrency_limit(mut self, max_in_flight: usize) -> Self {
+        self.execution_limit = Some(Arc::new(Semaphore::new(max_in_flight.max(1))));
+        self
+    }
+
+    /// Limit how many network tools (see [`Tool::requires_network`]) may
+    /// execute at once. Applies in addition to the overall limit.
+    pub fn with_network_concurrency_limit(mut self, max_in_flight: usize) -> Self {
+        self.network_limit = Some(Arc::new(Semaphore::new(max_in_flight.max(1))));
+        self
+    }
+
+    /// Wait for a free execution slot for `tool`.
+    ///
+    /// Hold the returned permit for the duration of `Tool::execute`. Returns
+    /// immediately when no limits are configured.
+    pub async fn acquire_execution_permit(&self, tool: &dyn Tool) -> ExecutionPermit {
+        // Take the narrower network permit first so a network tool waiting on
+        // its pool doesn't sit on a global slot that compute tools could use.
+        let network = match &self.network_limit {
+            Some(sem) if tool.requires_network() => Arc::clone(sem).acquire_owned().await.ok(),
+            _ => None,
+        };
+        let global = match &self.execution_limit {
+            Some(sem) => Arc::clone(sem).acquire_owned().await.ok(),
+            None => None,
+        };
+        ExecutionPermit {
+            _network: network,
+            _global: global,
         }
     }
 
@@ -166,6 +248,19 @@ impl ToolRegistry {
         }
     }
 
+    /// Register a built-in tool, wrapping it in a [`CachingTool`] when it is
+    /// cacheable and the result cache is enabled.
+    fn register_cacheable_sync<T: Tool + 'static>(&self, tool: T) {
+        match self.result_cache {
+            Some((max_entries, ttl)) if tool.is_cacheable() => self.register_sync(Arc::new(
+                CachingTool::new(tool)
+                    .with_max_entries(max_entries)
+                    .with_ttl(ttl),
+            )),
+            _ => self.register_sync(Arc::new(tool)),
+        }
+    }
+
     /// Unregister a tool.
     pub async fn unregister(&self, name: &str) -> Option<Arc<dyn Tool>> {
         self.tools.write().await.remove(name)
@@ -209,6 +304,23 @@ impl ToolRegistry {
         self.tools.read().await.values().cloned().collect()
     }
 
+    /// Group registered tool names by `Tool::category()`.
+    ///
+    /// Categories and the names within each are sorted for stable output.
+    pub async fn by_category(&self) -> BTreeMap<String, Vec<String>> {
+        let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for tool in self.tools.read().await.values() {
+            groups
+                .entry(tool.category().to_string())
+                .or_default()
+                .push(tool.name().to_string());
+        }
+        for names in groups.values_mut() {
+            names.sort_unstable();
+        }
+        groups
+    }
+
     /// Get the set of built-in tool names currently registered.
     pub async fn builtin_tool_names(&self) -> std::collections::HashSet<String> {
         self.builtin_names.read().await.clone()
@@ -240,7 +352,8 @@ impl ToolRegistry {
     pub fn register_builtin_tools(&self) {
         self.register_sync(Arc::new(EchoTool));
         self.register_sync(Arc::new(TimeTool));
-        self.register_sync(Arc::new(JsonTool));
+        self.register_cacheable_sync(JsonTool);
+        self.register_cacheable_sync(DataParseTool);
 
         let mut http = HttpTool::new();
         if let (Some(cr), Some(ss)) = (&self.credential_registry, &self.secrets_store) {
@@ -345,9 +458,11 @@ impl ToolRegistry {
         self.register_sync(Arc::new(MemorySearchTool::new(Arc::clone(&resolver))));
         self.register_sync(Arc::new(MemoryWriteTool::new(Arc::clone(&resolver))));
         self.register_sync(Arc::new(MemoryReadTool::new(Arc::clone(&resolver))));
-        self.register_sync(Arc::new(MemoryTreeTool::new(resolver)));
+        self.register_sync(Arc::new(MemoryTreeTool::new(Arc::clone(&resolver))));
+        self.register_sync(Arc::new(WorkspaceStatsTool::new(Arc::clone(&resolver))));
+        self.register_sync(Arc::new(BibliographyTool::new(resolver)));
 
-        tracing::debug!("Registered 4 memory tools");
+        tracing::debug!("Registered 6 memory tools");
     }
 
     /// Register memory tools with a fixed workspace (backward compatibility).
@@ -364,9 +479,15 @@ impl ToolRegistry {
         self.register_sync(Arc::new(MemoryReadTool::from_workspace(Arc::clone(
             &workspace,
         ))));
-        self.register_sync(Arc::new(MemoryTreeTool::from_workspace(workspace)));
+        self.register_sync(Arc::new(MemoryTreeTool::from_workspace(Arc::clone(
+            &workspace,
+        ))));
+        self.register_sync(Arc::new(WorkspaceStatsTool::from_workspace(Arc::clone(
+            &workspace,
+        ))));
+        self.register_sync(Arc::new(BibliographyTool::from_workspace(workspace)));
 
-        tracing::debug!("Registered 4 memory tools");
+        tracing::debug!("Registered 6 memory tools");
     }
 
     /// Register job management tools.
@@ -476,10 +597,11 @@ impl ToolRegistry {
         catalog: Arc<SkillCatalog>,
     ) {
         self.register_sync(Arc::new(SkillListTool::new(Arc::clone(&registry))));
-        self.register_sync(Arc::new(SkillSearchTool::new(
+        // Catalog entries come from an external registry.
+        self.register_sync(Arc::new(SanitizingTool::new(SkillSearchTool::new(
             Arc::clone(&registry),
             Arc::clone(&catalog),
-        )));
+        ))));
         self.register_sync(Arc::new(SkillInstallTool::new(
             Arc::clone(&registry),
             Arc::clone(&catalog),
@@ -674,6 +796,9 @@ impl ToolRegistry {
         if let Some(s) = reg.schema {
             wrapper = wrapper.with_schema(s);
         }
+        if let Some(version) = reg.version {
+            wrapper = wrapper.with_version(version);
+        }
         if let Some(store) = reg.secrets_store {
             wrapper = wrapper.with_secrets_store(store);
         }
@@ -748,6 +873,7 @@ impl ToolRegistry {
             limits: None,
             description: Some(&tool_with_binary.tool.description),
             schema: Some(tool_with_binary.tool.parameters_schema.clone()),
+            version: Some(&tool_with_binary.tool.version),
             secrets_store: self.secrets_store.clone(),
             oauth_refresh: None,
         })
@@ -791,6 +917,8 @@ pub struct WasmToolRegistration<'a> {
     pub description: Option<&'a str>,
     /// Optional parameter schema override.
     pub schema: Option<serde_json::Value>,
+    /// Optional contract version (defaults to `DEFAULT_TOOL_VERSION`).
+    pub version: Option<&'a str>,
     /// Secrets store for credential injection at request time.
     pub secrets_store: Option<Arc<dyn SecretsStore + Send + Sync>>,
     /// OAuth refresh configuration for auto-refreshing expired tokens.
@@ -1030,6 +1158,107 @@ mod tests {
         }
     }
 
+    struct CountingTool(Arc<std::sync::atomic::AtomicUsize>);
+
+    #[async_trait::async_trait]
+    impl Tool for CountingTool {
+        fn name(&self) -> &str {
+            "counting"
+        }
+        fn description(&self) -> &str {
+            "Counts executions"
+        }
+        fn parameters_schema(&self) -> serde_json::Value {
+            serde_json::json!({"type": "object"})
+        }
+        async fn execute(
+            &self,
+            params: serde_json::Value,
+            _: &crate::context::JobContext,
+        ) -> Result<ToolOutput, ToolError> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(ToolOutput::success(params, Duration::ZERO))
+        }
+        fn is_cacheable(&self) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cacheable_builtin_results_are_cached() {
+        let ctx = crate::context::JobContext::default();
+        let params = serde_json::json!({"x": 1});
+
+        for (registry, expected_calls) in [
+            (
+                ToolRegistry::new().with_result_cache(8, Duration::from_secs(60)),
+                1,
+            ),
+            (
+                ToolRegistry::new().with_result_cache(0, Duration::from_secs(60)),
+                2,
+            ),
+        ] {
+            let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            registry.register_cacheable_sync(CountingTool(Arc::clone(&calls)));
+            let tool = registry.get("counting").await.unwrap();
+            tool.execute(params.clone(), &ctx).await.unwrap();
+            tool.execute(params.clone(), &ctx).await.unwrap();
+            assert_eq!(
+                calls.load(std::sync::atomic::Ordering::SeqCst),
+                expected_calls
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_core_builtins_have_valid_examples() {
+        struct UnusedResolver;
+
+        #[async_trait::async_trait]
+        impl crate::tools::builtin::memory::WorkspaceResolver for UnusedResolver {
+            async fn resolve(&self, _user_id: &str) -> Arc<Workspace> {
+                unreachable!("examples() must not touch the workspace")
+            }
+        }
+
+        let registry = ToolRegistry::new();
+        registry.register_builtin_tools();
+        registry.register_dev_tools();
+        registry.register_memory_tools_with_resolver(Arc::new(UnusedResolver));
+
+        for name in [
+            "memory_search",
+            "memory_write",
+            "memory_read",
+            "memory_tree",
+            "http",
+            "shell",
+            "read_file",
+            "write_file",
+            "list_dir",
+            "apply_patch",
+        ] {
+            let tool = registry.get(name).await.unwrap();
+            let examples = tool.examples();
+            assert!(!examples.is_empty(), "{name} has no examples");
+
+            let schema = tool.parameters_schema();
+            let properties = schema["properties"].as_object().unwrap();
+            let required = schema["required"].as_array().cloned().unwrap_or_default();
+            for example in examples {
+                let params = example.params.as_object().unwrap();
+                for key in &required {
+                    let key = key.as_str().unwrap();
+                    assert!(params.contains_key(key), "{name}: example missing {key}");
+                }
+                for key in params.keys() {
+                    assert!(properties.contains_key(key), "{name}: unknown param {key}");
+                }
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_tool_definitions_sorted_alphabetically() {
         // Create tools with names that would NOT be alphabetical if inserted in this order.
@@ -1076,6 +1305,24 @@ mod tests {
         assert_eq!(names, vec!["alpha", "middle", "zebra"]);
     }
 
+    #[tokio::test]
+    async fn test_by_category_groups_builtin_tools() {
+        let registry = ToolRegistry::new();
+        registry.register_builtin_tools();
+
+        let groups = registry.by_category().await;
+        assert_eq!(groups.get("network"), Some(&vec!["http".to_string()]));
+        assert_eq!(
+            groups.get("general"),
+            Some(&vec![
+                "data_parse".to_string(),
+                "echo".to_string(),
+                "json".to_string(),
+                "time".to_string()
+            ])
+        );
+    }
+
     #[tokio::test]
     async fn test_retain_only_filters_tools() {
         let registry = ToolRegistry::new();
@@ -1098,4 +1345,49 @@ mod tests {
         let after = registry.list().await.len();
         assert_eq!(before, after);
     }
+
+    #[tokio::test]
+    async fn test_concurrency_limit_serializes_excess_executions() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let registry = Arc::new(ToolRegistry::new().with_concurrency_limit(2));
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let mut handles = Vec::new();
+        for _ in 0..6 {
+            let registry = Arc::clone(&registry);
+            let in_flight = Arc::clone(&in_flight);
+            let peak = Arc::clone(&peak);
+            handles.push(tokio::spawn(async move {
+                let _permit = registry.acquire_execution_permit(&EchoTool).await;
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+            }));
+        }
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_builtin_schemas_carry_semver_version() {
+        let registry = ToolRegistry::new();
+        registry.register_builtin_tools();
+        for tool in registry.all().await {
+            let schema = tool.schema();
+            assert_eq!(schema.version, tool.version(), "{}", tool.name());
+            let parts: Vec<&str> = schema.version.split('.').collect();
+            assert!(
+                parts.len() == 3 && parts.iter().all(|p| p.parse::<u64>().is_ok()),
+                "{} has non-semver version {:?}",
+                tool.name(),
+                schema.version
+            );
+        }
+    }
 }
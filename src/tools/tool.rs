@@ -167,8 +167,16 @@ pub enum ToolError {
     #[error("Not authorized: {0}")]
     NotAuthorized(String),
 
-    #[error("Rate limited, retry after {0:?}")]
-    RateLimited(Option<Duration>),
+    /// The tool or its upstream service is throttling requests.
+    ///
+    /// `retry_after` carries the server's `Retry-After` hint when one was
+    /// given, so callers can back off instead of retrying immediately.
+    /// `message` keeps the (truncated) explanation the server sent, if any.
+    #[error("Rate limited{}", rate_limit_details(.retry_after, .message))]
+    RateLimited {
+        retry_after: Option<Duration>,
+        message: Option<String>,
+    },
 
     #[error("External service error: {0}")]
     ExternalService(String),
@@ -177,6 +185,18 @@ pub enum ToolError {
     Sandbox(String),
 }
 
+fn rate_limit_details(retry_after: &Option<Duration>, message: &Option<String>) -> String {
+    let mut details = match retry_after {
+        Some(delay) => format!(", retry after {}s", delay.as_secs()),
+        None => String::new(),
+    };
+    if let Some(message) = message {
+        details.push_str(": ");
+        details.push_str(message);
+    }
+    details
+}
+
 /// Output from a tool execution.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolOutput {
@@ -189,6 +209,14 @@ pub struct ToolOutput {
     /// Raw output before sanitization (for debugging).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub raw: Option<String>,
+    /// Name of the tool that produced this output. Stamped by the execution
+    /// paths (see [`ToolOutput::with_attribution`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_name: Option<String>,
+    /// Wall-clock execution time in whole milliseconds, as measured by the
+    /// caller around `execute` (including any wait inside the tool).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
 }
 
 impl ToolOutput {
@@ -199,6 +227,8 @@ impl ToolOutput {
             cost: None,
             duration,
             raw: None,
+            tool_name: None,
+            duration_ms: None,
         }
     }
 
@@ -209,6 +239,8 @@ impl ToolOutput {
             cost: None,
             duration,
             raw: None,
+            tool_name: None,
+            duration_ms: None,
         }
     }
 
@@ -223,6 +255,16 @@ impl ToolOutput {
         self.raw = Some(raw.into());
         self
     }
+
+    /// Record which tool produced this output and how long the call took.
+    ///
+    /// Called by every execution path right after `Tool::execute` returns,
+    /// so consumers can attribute a result without extra context.
+    pub fn with_attribution(mut self, tool_name: impl Into<String>, elapsed: Duration) -> Self {
+        self.tool_name = Some(tool_name.into());
+        self.duration_ms = Some(elapsed.as_millis() as u64);
+        self
+    }
 }
 
 /// Definition of a tool's parameters using JSON Schema.
@@ -231,8 +273,18 @@ pub struct ToolSchema {
     pub name: String,
     pub description: String,
     pub parameters: serde_json::Value,
+    /// Semver of the tool's parameter/output contract (see [`Tool::version`]).
+    #[serde(default = "default_tool_version")]
+    pub version: String,
+}
+
+fn default_tool_version() -> String {
+    DEFAULT_TOOL_VERSION.to_string()
 }
 
+/// Version reported by tools that don't override [`Tool::version`].
+pub const DEFAULT_TOOL_VERSION: &str = "1.0.0";
+
 impl ToolSchema {
     /// Create a new tool schema.
     pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
@@ -244,6 +296,7 @@ impl ToolSchema {
                 "properties": {},
                 "required": []
             }),
+            version: default_tool_version(),
         }
     }
 
@@ -267,6 +320,32 @@ pub struct ToolDiscoverySummary {
     pub examples: Vec<serde_json::Value>,
 }
 
+/// A worked example of a tool invocation, used for few-shot prompting.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ToolExample {
+    /// What the example demonstrates.
+    pub description: String,
+    /// Sample parameters that validate against `parameters_schema()`.
+    pub params: serde_json::Value,
+    /// Sample result the tool returns for `params`.
+    pub output: serde_json::Value,
+}
+
+impl ToolExample {
+    /// Create a new example.
+    pub fn new(
+        description: impl Into<String>,
+        params: serde_json::Value,
+        output: serde_json::Value,
+    ) -> Self {
+        Self {
+            description: description.into(),
+            params,
+            output,
+        }
+    }
+}
+
 /// Trait for tools that the agent can use.
 #[async_trait]
 pub trait Tool: Send + Sync {
@@ -304,6 +383,27 @@ pub trait Tool: Send + Sync {
         true
     }
 
+    /// Whether this tool makes outbound network requests.
+    ///
+    /// Orthogonal to `requires_approval()`, which is about human sign-off.
+    /// A runtime can use this to skip network tools when offline without
+    /// also skipping tools that merely need approval.
+    ///
+    /// Default: `false`.
+    fn requires_network(&self) -> bool {
+        false
+    }
+
+    /// Whether identical params always produce an identical result.
+    ///
+    /// Pure tools (no I/O, no clock, no randomness) may return true so that
+    /// [`CachingTool`](crate::tools::CachingTool) can memoize them.
+    ///
+    /// Default: `false`.
+    fn is_cacheable(&self) -> bool {
+        false
+    }
+
     /// Risk level for a specific invocation of this tool.
     ///
     /// Defaults to `Low` (read-only, safe). Override for tools whose risk
@@ -343,6 +443,16 @@ pub trait Tool: Send + Sync {
         ToolDomain::Orchestrator
     }
 
+    /// Display group for this tool (e.g. "memory", "jobs", "network").
+    ///
+    /// Used to present tools grouped in prompts and UIs, and to scope a task
+    /// to a subset of tools. See `ToolRegistry::by_category()`.
+    ///
+    /// Default: `"general"`.
+    fn category(&self) -> &str {
+        "general"
+    }
+
     /// Parameter names whose values must be redacted before logging, hooks, and approvals.
     ///
     /// The agent framework replaces these parameter values with `"[REDACTED]"` before:
@@ -403,6 +513,35 @@ pub trait Tool: Send + Sync {
         None
     }
 
+    /// Semver of this tool's parameter and output contract.
+    ///
+    /// Bump the major version when a change would reject params that an
+    /// older client could send, so callers can detect the mismatch.
+    fn version(&self) -> &str {
+        DEFAULT_TOOL_VERSION
+    }
+
+    /// JSON Schema describing the shape of a successful result.
+    ///
+    /// The output counterpart of `parameters_schema()`. Surfaced by
+    /// `tool_info(detail: "schema")` so the model knows which fields to
+    /// expect instead of guessing from prose.
+    ///
+    /// Default: undeclared.
+    fn output_schema(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// Concrete invocation examples for few-shot prompting.
+    ///
+    /// Surfaced by `tool_info(detail: "summary")` so the model can see a
+    /// well-formed call before making one.
+    ///
+    /// Default: no examples.
+    fn examples(&self) -> Vec<ToolExample> {
+        Vec::new()
+    }
+
     /// Get the tool schema for LLM function calling.
     fn schema(&self) -> ToolSchema {
         let parameters = self.parameters_schema();
@@ -421,6 +560,7 @@ pub trait Tool: Send + Sync {
             name: self.name().to_string(),
             description,
             parameters,
+            version: self.version().to_string(),
         }
     }
 }
@@ -724,6 +864,19 @@ mod tests {
         assert_eq!(result.result, serde_json::json!("hello"));
     }
 
+    #[test]
+    fn test_with_attribution_serializes_name_and_duration() {
+        let output = ToolOutput::text("hi", Duration::from_millis(3))
+            .with_attribution("echo", Duration::from_millis(42));
+        let json = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["tool_name"], "echo");
+        assert_eq!(json["duration_ms"], 42);
+
+        let plain = serde_json::to_value(ToolOutput::text("hi", Duration::ZERO)).unwrap();
+        assert!(plain.get("tool_name").is_none());
+        assert!(plain.get("duration_ms").is_none());
+    }
+
     #[test]
     fn test_tool_schema() {
         let tool = EchoTool;
@@ -733,6 +886,33 @@ mod tests {
         assert!(!schema.description.is_empty());
     }
 
+    #[test]
+    fn test_rate_limited_display_includes_retry_hint() {
+        let err = ToolError::RateLimited {
+            retry_after: Some(Duration::from_secs(30)),
+            message: None,
+        };
+        assert_eq!(err.to_string(), "Rate limited, retry after 30s");
+
+        let err = ToolError::RateLimited {
+            retry_after: None,
+            message: Some("quota exceeded".to_string()),
+        };
+        assert_eq!(err.to_string(), "Rate limited: quota exceeded");
+    }
+
+    #[test]
+    fn test_category_default() {
+        let tool = EchoTool;
+        assert_eq!(tool.category(), "general");
+    }
+
+    #[test]
+    fn test_requires_network_default() {
+        let tool = EchoTool;
+        assert!(!tool.requires_network());
+    }
+
     #[test]
     fn test_execution_timeout_default() {
         let tool = EchoTool;
@@ -585,6 +585,8 @@ pub struct WasmToolWrapper {
     description: String,
     /// Compact and discovery schemas for this tool.
     schemas: WasmToolSchemas,
+    /// Contract version, from the capabilities file or wasm storage.
+    version: String,
     /// Injected credentials for HTTP requests (e.g., OAuth tokens).
     /// Keys are placeholder names like "GOOGLE_ACCESS_TOKEN".
     credentials: HashMap<String, String>,
@@ -836,6 +838,7 @@ impl WasmToolWrapper {
         Self {
             description: prepared.description.clone(),
             schemas: WasmToolSchemas::new(prepared.schema.clone()),
+            version: crate::tools::tool::DEFAULT_TOOL_VERSION.to_string(),
             runtime,
             prepared,
             capabilities,
@@ -862,6 +865,12 @@ impl WasmToolWrapper {
         self
     }
 
+    /// Set the tool's contract version (semver).
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+
     /// Override the parameter schema.
     pub fn with_schema(mut self, schema: serde_json::Value) -> Self {
         let override_typed = WasmToolSchemas::typed_property_count(&schema);
@@ -1090,6 +1099,10 @@ impl Tool for WasmToolWrapper {
         &self.description
     }
 
+    fn version(&self) -> &str {
+        &self.version
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         self.schemas.advertised()
     }
@@ -1117,6 +1130,7 @@ impl Tool for WasmToolWrapper {
             name: self.prepared.name.clone(),
             description,
             parameters: self.schemas.advertised(),
+            version: self.version().to_string(),
         }
     }
 
@@ -1204,6 +1218,10 @@ impl Tool for WasmToolWrapper {
         true
     }
 
+    fn requires_network(&self) -> bool {
+        self.capabilities.http.is_some()
+    }
+
     fn estimated_duration(&self, _params: &serde_json::Value) -> Option<Duration> {
         // Use the timeout as a conservative estimate
         Some(self.prepared.limits.timeout)
@@ -1988,6 +2006,22 @@ mod tests {
         assert!(runtime.config().fuel_config.enabled);
     }
 
+    #[tokio::test]
+    async fn test_version_defaults_and_override() {
+        let runtime = Arc::new(WasmToolRuntime::new(WasmRuntimeConfig::for_testing()).unwrap());
+        let prepared = runtime
+            .prepare("versioned", b"\0asm\x0d\0\x01\0", None)
+            .await
+            .unwrap();
+        let wrapper =
+            super::WasmToolWrapper::new(Arc::clone(&runtime), prepared, Capabilities::default());
+        assert_eq!(wrapper.version(), crate::tools::tool::DEFAULT_TOOL_VERSION);
+
+        let wrapper = wrapper.with_version("2.3.1");
+        assert_eq!(wrapper.version(), "2.3.1");
+        assert_eq!(wrapper.schema().version, "2.3.1");
+    }
+
     #[tokio::test]
     async fn test_advertised_schema_auto_compacted_from_discovery() {
         let discovery_schema = serde_json::json!({
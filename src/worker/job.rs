@@ -519,6 +519,12 @@ Report when the job is complete or if you encounter issues you cannot resolve."#
             job_ctx.http_interceptor = deps.http_interceptor.clone();
         }
 
+        if let Some(err) =
+            crate::tools::execute::offline_violation(&deps.tools, tool.as_ref(), &job_ctx)
+        {
+            return Err(err.into());
+        }
+
         // Check approval: use context-aware check if available, else block all non-Never tools
         let requirement = tool.requires_approval(&normalized_params);
         let blocked =
@@ -538,6 +544,7 @@ Report when the job is complete or if you encounter issues you cannot resolve."#
             return Err(crate::error::ToolError::RateLimited {
                 name: tool_name.to_string(),
                 retry_after: Some(retry_after),
+                message: None,
             }
             .into());
         }
@@ -625,6 +632,7 @@ Report when the job is complete or if you encounter issues you cannot resolve."#
         );
 
         // Execute with per-tool timeout and timing
+        let permit = deps.tools.acquire_execution_permit(tool.as_ref()).await;
         let tool_timeout = tool.execution_timeout();
         let start = std::time::Instant::now();
         let result = tokio::time::timeout(tool_timeout, async {
@@ -632,6 +640,8 @@ Report when the job is complete or if you encounter issues you cannot resolve."#
         })
         .await;
         let elapsed = start.elapsed();
+        drop(permit);
+        let result = result.map(|r| r.map(|output| output.with_attribution(tool_name, elapsed)));
 
         match &result {
             Ok(Ok(output)) => {
@@ -77,6 +77,59 @@ pub struct WriteResult {
     pub actual_layer: String,
 }
 
+/// Storage usage for a workspace, grouped by top-level path prefix.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct WorkspaceUsage {
+    pub total_files: usize,
+    pub total_bytes: u64,
+    /// Keyed by top-level directory (e.g. `daily/`). Files at the workspace
+    /// root are grouped under `/`.
+    pub by_prefix: BTreeMap<String, PrefixUsage>,
+}
+
+/// File count and byte total for one top-level prefix.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+pub struct PrefixUsage {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// A window of a document's content, returned by [`Workspace::read_range`].
+///
+/// Offsets and lengths count characters, not bytes, so a slice never splits
+/// a multi-byte character.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct DocumentSlice {
+    pub path: String,
+    pub content: String,
+    /// Character offset of `content` within the document.
+    pub offset: usize,
+    /// Length of the whole document in characters.
+    pub total_chars: usize,
+    /// Whether the document continues past this slice.
+    pub has_more: bool,
+}
+
+/// Exclusive hold on one document path, returned by
+/// [`Workspace::lock_for_update`].
+///
+/// While it is held, other writes to the same path through this workspace
+/// (or its scoped clones) wait. Pass it to [`Workspace::write_locked`] to
+/// finish a read-modify-write.
+pub struct PathLock {
+    scope: String,
+    path: String,
+    _guard: tokio::sync::OwnedMutexGuard<()>,
+}
+
+impl PathLock {
+    /// The normalized path this lock covers.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
 use chrono::{NaiveDate, Utc};
@@ -245,6 +298,18 @@ impl WorkspaceStorage {
         }
     }
 
+    async fn list_document_sizes(
+        &self,
+        user_id: &str,
+        agent_id: Option<Uuid>,
+    ) -> Result<Vec<(String, u64)>, WorkspaceError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            Self::Repo(repo) => repo.list_document_sizes(user_id, agent_id).await,
+            Self::Db(db) => db.list_document_sizes(user_id, agent_id).await,
+        }
+    }
+
     async fn delete_chunks(&self, document_id: Uuid) -> Result<(), WorkspaceError> {
         match self {
             #[cfg(feature = "postgres")]
@@ -419,8 +484,15 @@ pub struct Workspace {
     /// Optional privacy classifier for shared layer writes.
     /// When None, writes go exactly where requested — no silent redirect.
     privacy_classifier: Option<Arc<dyn crate::workspace::privacy::PrivacyClassifier>>,
+    /// Per-document advisory locks, keyed by (scope, path). Held across
+    /// read-modify-write so concurrent updates to one document serialize
+    /// while writes to different documents proceed in parallel. Shared with
+    /// every clone made by [`scoped_to_user`](Self::scoped_to_user).
+    path_locks: Arc<PathLocks>,
 }
 
+type PathLocks = std::sync::Mutex<HashMap<(String, String), Arc<tokio::sync::Mutex<()>>>>;
+
 impl Workspace {
     /// Create a new workspace backed by a PostgreSQL connection pool.
     #[cfg(feature = "postgres")]
@@ -438,6 +510,7 @@ impl Workspace {
             search_defaults: SearchConfig::default(),
             memory_layers,
             privacy_classifier: None,
+            path_locks: Arc::default(),
         }
     }
 
@@ -458,6 +531,7 @@ impl Workspace {
             search_defaults: SearchConfig::default(),
             memory_layers,
             privacy_classifier: None,
+            path_locks: Arc::default(),
         }
     }
 
@@ -628,6 +702,7 @@ impl Workspace {
             search_defaults: self.search_defaults.clone(),
             memory_layers,
             privacy_classifier: self.privacy_classifier.clone(),
+            path_locks: Arc::clone(&self.path_locks),
         }
     }
 
@@ -680,6 +755,28 @@ impl Workspace {
         }
     }
 
+    /// Read up to `limit` characters of a file starting at character `offset`.
+    ///
+    /// Lets callers page through a large document without putting all of it
+    /// into a context window. Scope resolution matches [`read`](Self::read).
+    pub async fn read_range(
+        &self,
+        path: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<DocumentSlice, WorkspaceError> {
+        let doc = self.read(path).await?;
+        let total_chars = doc.content.chars().count();
+        let content: String = doc.content.chars().skip(offset).take(limit).collect();
+        Ok(DocumentSlice {
+            path: doc.path,
+            content,
+            offset,
+            total_chars,
+            has_more: offset.saturating_add(limit) < total_chars,
+        })
+    }
+
     /// Read a file from the **primary scope only**, ignoring additional read scopes.
     ///
     /// Use this for identity and configuration files (AGENTS.md, SOUL.md, USER.md,
@@ -706,14 +803,45 @@ impl Workspace {
     /// workspace.write("projects/alpha/README.md", "# Project Alpha\n\nDescription here.").await?;
     /// ```
     pub async fn write(&self, path: &str, content: &str) -> Result<MemoryDocument, WorkspaceError> {
+        let lock = self.lock_for_update(path).await;
+        self.write_locked(&lock, content).await
+    }
+
+    /// Lock `path` for a read-modify-write.
+    ///
+    /// Read the document as usual, then write it back with
+    /// [`write_locked`](Self::write_locked) before dropping the lock. Plain
+    /// [`write`](Self::write) and [`append`](Self::append) to the same path
+    /// wait until the lock is dropped, so updates are not lost.
+    pub async fn lock_for_update(&self, path: &str) -> PathLock {
         let path = normalize_path(path);
+        let guard = self.lock_path(&self.user_id, &path).await;
+        PathLock {
+            scope: self.user_id.clone(),
+            path,
+            _guard: guard,
+        }
+    }
+
+    /// Write the document covered by `lock`, which must have been taken from
+    /// this workspace with [`lock_for_update`](Self::lock_for_update).
+    pub async fn write_locked(
+        &self,
+        lock: &PathLock,
+        content: &str,
+    ) -> Result<MemoryDocument, WorkspaceError> {
+        debug_assert_eq!(
+            lock.scope, self.user_id,
+            "PathLock used with a workspace for a different scope"
+        );
+        let path = lock.path.as_str();
         // Scan system-prompt-injected files for prompt injection.
-        if is_system_prompt_file(&path) && !content.is_empty() {
-            reject_if_injected(&path, content)?;
+        if is_system_prompt_file(path) && !content.is_empty() {
+            reject_if_injected(path, content)?;
         }
         let doc = self
             .storage
-            .get_or_create_document_by_path(&self.user_id, self.agent_id, &path)
+            .get_or_create_document_by_path(&self.user_id, self.agent_id, path)
             .await?;
         self.storage.update_document(doc.id, content).await?;
         self.reindex_document(doc.id).await?;
@@ -729,18 +857,47 @@ impl Workspace {
     /// For semantic separation (e.g., memory entries), use `append_memory()`
     /// which uses `\n\n`.
     ///
-    /// Uses a read-modify-write pattern that is not concurrency-safe:
-    /// concurrent appends to the same path may lose writes.
+    /// The read-modify-write holds this workspace's lock for the path, so
+    /// concurrent appends through the same `Workspace` (or clones made with
+    /// [`scoped_to_user`](Self::scoped_to_user)) never lose entries.
+    /// Appends through separately constructed instances or other processes
+    /// are not coordinated.
     pub async fn append(&self, path: &str, content: &str) -> Result<(), WorkspaceError> {
+        self.append_impl(path, content, true).await
+    }
+
+    /// Append content to a file that must already exist.
+    ///
+    /// Same as [`append`](Self::append), but returns
+    /// [`WorkspaceError::DocumentNotFound`] instead of creating the file.
+    /// Use it after a caller has checked the file exists: if the file is
+    /// deleted in between, the entry fails instead of starting a new,
+    /// headerless document.
+    pub async fn append_existing(&self, path: &str, content: &str) -> Result<(), WorkspaceError> {
+        self.append_impl(path, content, false).await
+    }
+
+    async fn append_impl(
+        &self,
+        path: &str,
+        content: &str,
+        create: bool,
+    ) -> Result<(), WorkspaceError> {
         let path = normalize_path(path);
+        let _guard = self.lock_path(&self.user_id, &path).await;
         // Scan system-prompt-injected files for prompt injection.
         if is_system_prompt_file(&path) && !content.is_empty() {
             reject_if_injected(&path, content)?;
         }
-        let doc = self
-            .storage
-            .get_or_create_document_by_path(&self.user_id, self.agent_id, &path)
-            .await?;
+        let doc = if create {
+            self.storage
+                .get_or_create_document_by_path(&self.user_id, self.agent_id, &path)
+                .await?
+        } else {
+            self.storage
+                .get_document_by_path(&self.user_id, self.agent_id, &path)
+                .await?
+        };
 
         let new_content = if doc.content.is_empty() {
             content.to_string()
@@ -836,6 +993,7 @@ impl Workspace {
         let (scope, actual_layer, redirected) =
             self.resolve_layer_target(layer_name, content, force)?;
         let path = normalize_path(path);
+        let _guard = self.lock_path(&scope, &path).await;
         let doc = self
             .storage
             .get_or_create_document_by_path(&scope, self.agent_id, &path)
@@ -863,8 +1021,8 @@ impl Workspace {
     /// shared document at that path. The `WriteResult::redirected` flag
     /// indicates when this has happened.
     ///
-    /// Uses a read-modify-write pattern that is not concurrency-safe:
-    /// concurrent appends to the same path may lose writes.
+    /// Like [`append`](Self::append), the read-modify-write is serialized
+    /// per path within this workspace and its scoped clones only.
     pub async fn append_to_layer(
         &self,
         layer_name: &str,
@@ -875,6 +1033,7 @@ impl Workspace {
         let (scope, actual_layer, redirected) =
             self.resolve_layer_target(layer_name, content, force)?;
         let path = normalize_path(path);
+        let _guard = self.lock_path(&scope, &path).await;
         let doc = self
             .storage
             .get_or_create_document_by_path(&scope, self.agent_id, &path)
@@ -894,6 +1053,23 @@ impl Workspace {
         })
     }
 
+    /// Acquire the advisory lock for `path` in `scope`.
+    ///
+    /// Idle entries are pruned on each call so the map stays proportional to
+    /// the number of documents currently being written.
+    async fn lock_path(&self, scope: &str, path: &str) -> tokio::sync::OwnedMutexGuard<()> {
+        let lock = {
+            let mut locks = self.path_locks.lock().unwrap_or_else(|e| e.into_inner());
+            locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+            Arc::clone(
+                locks
+                    .entry((scope.to_string(), path.to_string()))
+                    .or_default(),
+            )
+        };
+        lock.lock_owned().await
+    }
+
     /// Check if a file exists.
     ///
     /// When multi-scope reads are configured, checks across all read scopes.
@@ -999,6 +1175,31 @@ impl Workspace {
         }
     }
 
+    /// Report file counts and byte totals per top-level prefix.
+    ///
+    /// Sizes are computed by the storage backend, so document contents are
+    /// never loaded. Only the primary scope is counted, since that is the
+    /// only scope this workspace writes to.
+    pub async fn usage(&self) -> Result<WorkspaceUsage, WorkspaceError> {
+        let sizes = self
+            .storage
+            .list_document_sizes(&self.user_id, self.agent_id)
+            .await?;
+        let mut usage = WorkspaceUsage::default();
+        for (path, bytes) in sizes {
+            let prefix = match path.split_once('/') {
+                Some((top, _)) => format!("{top}/"),
+                None => "/".to_string(),
+            };
+            let entry = usage.by_prefix.entry(prefix).or_default();
+            entry.files += 1;
+            entry.bytes += bytes;
+            usage.total_files += 1;
+            usage.total_bytes += bytes;
+        }
+        Ok(usage)
+    }
+
     // ==================== Convenience Methods ====================
 
     /// Get the main MEMORY.md document (long-term curated memory).
@@ -2108,6 +2309,172 @@ mod seed_tests {
         );
     }
 
+    #[tokio::test]
+    async fn usage_groups_files_by_top_level_prefix() {
+        let (ws, _dir) = create_test_workspace().await;
+
+        ws.write("projects/alpha/notes.md", "alpha notes")
+            .await
+            .expect("write alpha");
+        ws.write("projects/beta.md", "beta")
+            .await
+            .expect("write beta");
+        ws.write("scratch.md", "é").await.expect("write scratch");
+
+        let usage = ws.usage().await.expect("usage");
+        assert_eq!(usage.total_files, 3);
+        assert_eq!(
+            usage.by_prefix.get("projects/"),
+            Some(&PrefixUsage {
+                files: 2,
+                bytes: 15
+            })
+        );
+        // Byte totals, not char counts: "é" is two bytes in UTF-8.
+        assert_eq!(
+            usage.by_prefix.get("/"),
+            Some(&PrefixUsage { files: 1, bytes: 2 })
+        );
+        assert_eq!(usage.total_bytes, 17);
+    }
+
+    #[tokio::test]
+    async fn read_range_pages_on_char_boundaries() {
+        let (ws, _dir) = create_test_workspace().await;
+        // 250 two-byte chars: byte offsets would split them, char offsets can't.
+        let content = "é".repeat(250);
+        ws.write("reports/long.md", &content).await.expect("write");
+
+        let first = ws
+            .read_range("reports/long.md", 0, 100)
+            .await
+            .expect("first");
+        assert_eq!(first.content, "é".repeat(100));
+        assert_eq!(first.total_chars, 250);
+        assert!(first.has_more);
+
+        let second = ws
+            .read_range("reports/long.md", 100, 100)
+            .await
+            .expect("second");
+        assert_eq!(second.offset, 100);
+        assert_eq!(second.content, "é".repeat(100));
+        assert!(second.has_more);
+
+        let last = ws
+            .read_range("reports/long.md", 200, 100)
+            .await
+            .expect("last");
+        assert_eq!(last.content.chars().count(), 50);
+        assert!(!last.has_more);
+    }
+
+    #[tokio::test]
+    async fn concurrent_appends_to_one_path_keep_every_entry() {
+        let (ws, _dir) = create_test_workspace().await;
+        let ws = Arc::new(ws);
+
+        let mut handles = Vec::new();
+        for i in 0..8 {
+            let ws = Arc::clone(&ws);
+            handles.push(tokio::spawn(async move {
+                ws.append("notes/log.md", &format!("entry {i}")).await
+            }));
+        }
+        for handle in handles {
+            handle.await.expect("join").expect("append");
+        }
+
+        let content = ws.read("notes/log.md").await.expect("read").content;
+        let mut lines: Vec<&str> = content.lines().collect();
+        lines.sort_unstable();
+        let expected: Vec<String> = (0..8).map(|i| format!("entry {i}")).collect();
+        assert_eq!(lines, expected);
+    }
+
+    #[tokio::test]
+    async fn concurrent_appends_through_scoped_clones_keep_every_entry() {
+        let (ws, _dir) = create_test_workspace().await;
+        let user_id = ws.user_id().to_string();
+        let first = Arc::new(ws.scoped_to_user(&user_id));
+        let second = Arc::new(ws.scoped_to_user(&user_id));
+
+        let mut handles = Vec::new();
+        for i in 0..8 {
+            let ws = if i % 2 == 0 {
+                Arc::clone(&first)
+            } else {
+                Arc::clone(&second)
+            };
+            handles.push(tokio::spawn(async move {
+                ws.append("notes/log.md", &format!("entry {i}")).await
+            }));
+        }
+        for handle in handles {
+            handle.await.expect("join").expect("append");
+        }
+
+        let content = ws.read("notes/log.md").await.expect("read").content;
+        let mut lines: Vec<&str> = content.lines().collect();
+        lines.sort_unstable();
+        let expected: Vec<String> = (0..8).map(|i| format!("entry {i}")).collect();
+        assert_eq!(lines, expected);
+    }
+
+    #[tokio::test]
+    async fn lock_for_update_holds_off_other_writes() {
+        let (ws, _dir) = create_test_workspace().await;
+        let ws = Arc::new(ws);
+        ws.write("notes/lib.json", "1").await.expect("write");
+
+        let lock = ws.lock_for_update("notes/lib.json").await;
+        let writer = {
+            let ws = Arc::clone(&ws);
+            tokio::spawn(async move { ws.append("notes/lib.json", "other").await })
+        };
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert!(!writer.is_finished(), "append must wait for the lock");
+
+        let current = ws.read("notes/lib.json").await.expect("read").content;
+        ws.write_locked(&lock, &format!("{current}2"))
+            .await
+            .expect("write_locked");
+        drop(lock);
+
+        writer.await.expect("join").expect("append");
+        assert_eq!(
+            ws.read("notes/lib.json").await.expect("read").content,
+            "12\nother"
+        );
+    }
+
+    #[tokio::test]
+    async fn append_existing_refuses_to_create_missing_file() {
+        let (ws, _dir) = create_test_workspace().await;
+
+        ws.write("notes/exp.md", "# Experiment")
+            .await
+            .expect("write");
+        ws.append_existing("notes/exp.md", "- observation 1")
+            .await
+            .expect("append to existing");
+        assert_eq!(
+            ws.read("notes/exp.md").await.expect("read").content,
+            "# Experiment\n- observation 1"
+        );
+
+        ws.delete("notes/exp.md").await.expect("delete");
+        let err = ws
+            .append_existing("notes/exp.md", "- observation 2")
+            .await
+            .expect_err("missing file");
+        assert!(matches!(err, WorkspaceError::DocumentNotFound { .. }));
+        assert!(matches!(
+            ws.read("notes/exp.md").await,
+            Err(WorkspaceError::DocumentNotFound { .. })
+        ));
+    }
+
     #[test]
     fn test_default_single_scope() {
         // Verify backward compatibility: default workspace has single read scope
@@ -268,6 +268,38 @@ impl Repository {
         Ok(rows.iter().map(|r| self.row_to_document(r)).collect())
     }
 
+    /// List all document paths with their content size in bytes.
+    pub async fn list_document_sizes(
+        &self,
+        user_id: &str,
+        agent_id: Option<Uuid>,
+    ) -> Result<Vec<(String, u64)>, WorkspaceError> {
+        let conn = self.conn().await?;
+
+        let rows = conn
+            .query(
+                r#"
+                SELECT path, octet_length(content)::bigint AS size
+                FROM memory_documents
+                WHERE user_id = $1 AND agent_id IS NOT DISTINCT FROM $2
+                ORDER BY path
+                "#,
+                &[&user_id, &agent_id],
+            )
+            .await
+            .map_err(|e| WorkspaceError::SearchFailed {
+                reason: format!("List document sizes failed: {}", e),
+            })?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                let size: i64 = row.get("size");
+                (row.get("path"), size.max(0) as u64)
+            })
+            .collect())
+    }
+
     fn row_to_document(&self, row: &tokio_postgres::Row) -> MemoryDocument {
         MemoryDocument {
             id: row.get("id"),
@@ -41,7 +41,10 @@ mod tests {
             _params: serde_json::Value,
             _ctx: &JobContext,
         ) -> Result<ToolOutput, ToolError> {
-            Err(ToolError::RateLimited(Some(Duration::from_secs(60))))
+            Err(ToolError::RateLimited {
+                retry_after: Some(Duration::from_secs(60)),
+                message: None,
+            })
         }
     }
 
@@ -10,7 +10,7 @@ use ironclaw::tools::validate_tool_schema;
 use ironclaw::tools::{Tool, ToolRegistry};
 
 /// Validate schemas of all tools registered via `register_builtin_tools()` and
-/// `register_dev_tools()` (echo, time, json, http, shell, file tools).
+/// `register_dev_tools()` (echo, time, json, data_parse, http, shell, file tools).
 ///
 /// These tools can be constructed without external dependencies (no DB, no
 /// workspace, no extension manager). Tools requiring dependencies (memory, job,
@@ -61,6 +61,7 @@ async fn core_registration_covers_expected_tools() {
 
     let expected = &[
         "apply_patch",
+        "data_parse",
         "echo",
         "http",
         "json",